@@ -1,4 +1,4 @@
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
 use serde::de::DeserializeOwned;
 use std::borrow::Cow;
 use std::fmt::{self, Display};
@@ -8,36 +8,54 @@ use tokio::sync::mpsc;
 use tracing::{debug, warn};
 
 mod error;
-use crate::io::{read_lines, StdIoKind};
+use crate::io::{read_lines, ReadLinesError, StdIoKind};
 
 pub use self::error::*;
 
+/// The replacement of a sensitive value in logs
+pub(crate) const REDACTED: &str = "***";
+
 #[derive(Debug, Clone)]
 pub(crate) struct Cmd<'a> {
-    command: &'a str,
+    command: Cow<'a, str>,
     args: Vec<String>,
     dir: Option<&'a Path>,
     env: IndexMap<String, String>,
+    safe_env: IndexSet<String>,
     ignore_stderr: bool,
 }
 
 impl<'a> Cmd<'a> {
-    pub(crate) fn new(command: &'a str) -> Self {
+    pub(crate) fn new(command: impl Into<Cow<'a, str>>) -> Self {
         Self {
-            command,
+            command: command.into(),
             args: vec![],
             dir: None,
             env: IndexMap::new(),
+            safe_env: IndexSet::new(),
             ignore_stderr: false,
         }
     }
 
+    /// Replace the program, e.g. to use a standalone compose binary
+    pub(crate) fn set_program(&mut self, command: impl Into<Cow<'a, str>>) {
+        self.command = command.into();
+    }
+
     pub(crate) fn with_dir(&mut self, path: &'a Path) {
         self.dir = Some(path);
     }
 
-    pub(crate) fn set_env(&mut self, env: IndexMap<String, String>) {
-        self.env = env;
+    /// Add an environment variable with a safe value, displayed as is
+    pub(crate) fn push_safe_env(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        let key = key.into();
+        self.safe_env.insert(key.clone());
+        self.env.insert(key, value.into());
+    }
+
+    /// Add environment variables, their values are masked in the display
+    pub(crate) fn push_envs(&mut self, env: IndexMap<String, String>) {
+        self.env.extend(env);
     }
 
     pub(crate) fn ignore_stderr(&mut self) {
@@ -105,13 +123,71 @@ impl<'a> Cmd<'a> {
                 source,
             })
     }
+
+    fn handle_json_list<T>(&self, output: Output) -> Result<Vec<T>, CommandError>
+    where
+        T: DeserializeOwned,
+    {
+        parse_json_list(&output.stdout).map_err(|source| CommandError::SerdeError {
+            command: format!("{self}"),
+            output,
+            source,
+        })
+    }
+
+    fn handle_json_or_default<T>(&self, output: Output) -> Result<T, CommandError>
+    where
+        T: DeserializeOwned + Default,
+    {
+        parse_json_or_default(&output.stdout).map_err(|source| CommandError::SerdeError {
+            command: format!("{self}"),
+            output,
+            source,
+        })
+    }
+}
+
+/// Parse a JSON value, an empty output or a `null` yield the default value
+///
+/// A `--format={{json .Path}}` output is `null` when the path is not set,
+/// e.g. the networks of a container started with the `none` network.
+pub(crate) fn parse_json_or_default<T>(bytes: &[u8]) -> Result<T, serde_json::Error>
+where
+    T: DeserializeOwned + Default,
+{
+    if bytes.iter().all(u8::is_ascii_whitespace) {
+        return Ok(T::default());
+    }
+    let result = serde_json::from_slice::<Option<T>>(bytes)?;
+    Ok(result.unwrap_or_default())
+}
+
+/// Parse a list of JSON objects, either a JSON array, or a JSON object per line
+///
+/// The runners output format changes across versions (and runners),
+/// e.g. `podman ps --format=json` provides an array, when `docker ps --format='{{json .}}'` provides an object per line.
+pub(crate) fn parse_json_list<T>(bytes: &[u8]) -> Result<Vec<T>, serde_json::Error>
+where
+    T: DeserializeOwned,
+{
+    let is_array = bytes
+        .iter()
+        .find(|b| !b.is_ascii_whitespace())
+        .is_some_and(|b| *b == b'[');
+    if is_array {
+        serde_json::from_slice(bytes)
+    } else {
+        serde_json::Deserializer::from_slice(bytes)
+            .into_iter::<T>()
+            .collect()
+    }
 }
 
 // Blocking API
 impl Cmd<'_> {
     fn output_blocking(&self) -> Result<Output, CommandError> {
         debug!("Running blocking command\n{self}");
-        let mut cmd: std::process::Command = std::process::Command::new(self.command);
+        let mut cmd: std::process::Command = std::process::Command::new(self.command.as_ref());
         cmd.envs(&self.env);
         if let Some(dir) = self.dir {
             cmd.current_dir(dir);
@@ -142,14 +218,20 @@ impl Cmd<'_> {
 
 // Async API
 impl Cmd<'_> {
-    async fn output(&self) -> Result<Output, CommandError> {
+    async fn raw_output(&self) -> std::io::Result<Output> {
         debug!("Running command\n{self}");
-        let mut cmd = tokio::process::Command::new(self.command);
+        let mut cmd = tokio::process::Command::new(self.command.as_ref());
+        // The process is killed if the future is dropped, e.g. on a start timeout
+        cmd.kill_on_drop(true);
         cmd.envs(&self.env);
         if let Some(dir) = self.dir {
             cmd.current_dir(dir);
         }
-        let output = cmd.args(&self.args).output().await;
+        cmd.args(&self.args).output().await
+    }
+
+    async fn output(&self) -> Result<Output, CommandError> {
+        let output = self.raw_output().await;
         self.handle_output(output)
     }
 
@@ -159,7 +241,9 @@ impl Cmd<'_> {
         tx: mpsc::Sender<String>,
     ) -> Result<Output, CommandError> {
         debug!("Running command\n{self}");
-        let mut cmd = tokio::process::Command::new(self.command);
+        let mut cmd = tokio::process::Command::new(self.command.as_ref());
+        // The process is killed if the future is dropped, e.g. on a start timeout
+        cmd.kill_on_drop(true);
         cmd.envs(&self.env);
         if let Some(dir) = self.dir {
             cmd.current_dir(dir);
@@ -178,8 +262,17 @@ impl Cmd<'_> {
         let result = match io {
             StdIoKind::Out => read_lines(child.stdout.take(), tx).await,
             StdIoKind::Err => read_lines(child.stderr.take(), tx).await,
+            StdIoKind::Both => {
+                // Read both pipes concurrently into the same channel
+                let stderr_task = tokio::spawn(read_lines(child.stderr.take(), tx.clone()));
+                let stdout_result = read_lines(child.stdout.take(), tx).await;
+                let stderr_result = stderr_task.await.map_err(ReadLinesError::from);
+                stdout_result.and(stderr_result.and_then(|result| result))
+            }
         };
         if let Err(source) = result {
+            // The child could run forever, e.g. following the logs
+            _ = child.start_kill();
             return Err(CommandError::CommandWatchFail {
                 command: self.to_string(),
                 source,
@@ -196,6 +289,49 @@ impl Cmd<'_> {
         Ok(result)
     }
 
+    /// The raw stdout, useful for binary outputs
+    pub(super) async fn raw_result(&self) -> Result<Vec<u8>, CommandError> {
+        let output = self.output().await?;
+        Ok(output.stdout)
+    }
+
+    /// The exit code and the stdout, a non-zero exit code is not an error
+    pub(super) async fn exit_code_and_result(&self) -> Result<(i32, String), CommandError> {
+        let (exit_code, stdout, _) = self.exit_code_and_outputs().await?;
+        Ok((exit_code, stdout))
+    }
+
+    /// The exit code, the stdout, and the stderr, a non-zero exit code is not an error
+    pub(super) async fn exit_code_and_outputs(
+        &self,
+    ) -> Result<(i32, String, String), CommandError> {
+        let output =
+            self.raw_output()
+                .await
+                .map_err(|source| CommandError::CommandProcessError {
+                    command: self.to_string(),
+                    source,
+                })?;
+        // No exit code if the process is terminated by a signal
+        let Some(exit_code) = output.status.code() else {
+            let command = self.to_string();
+            return Err(CommandError::CommandFail { command, output });
+        };
+        let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+        let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+        Ok((exit_code, stdout, stderr))
+    }
+
+    /// The stdout followed by the stderr
+    pub(super) async fn result_with_stderr(mut self) -> Result<String, CommandError> {
+        self.ignore_stderr();
+        let output = self.output().await?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let result = format!("{stdout}{stderr}");
+        Ok(result)
+    }
+
     pub(super) async fn json<T>(self) -> Result<T, CommandError>
     where
         T: DeserializeOwned,
@@ -204,6 +340,15 @@ impl Cmd<'_> {
         self.handle_json(output)
     }
 
+    /// Read a JSON value, see [`parse_json_or_default`]
+    pub(super) async fn json_or_default<T>(self) -> Result<T, CommandError>
+    where
+        T: DeserializeOwned + Default,
+    {
+        let output = self.output().await?;
+        self.handle_json_or_default(output)
+    }
+
     pub(super) async fn json_stream<T>(self) -> Result<Vec<T>, CommandError>
     where
         T: DeserializeOwned,
@@ -212,6 +357,15 @@ impl Cmd<'_> {
         self.handle_json_stream(output)
     }
 
+    /// Read a list of JSON objects, see [`parse_json_list`]
+    pub(super) async fn json_list<T>(self) -> Result<Vec<T>, CommandError>
+    where
+        T: DeserializeOwned,
+    {
+        let output = self.output().await?;
+        self.handle_json_list(output)
+    }
+
     pub(super) async fn status(self) -> Result<ExitStatus, CommandError> {
         let output = self.output().await?;
         Ok(output.status)
@@ -228,6 +382,14 @@ pub(crate) fn escape_arg(arg: &str) -> Cow<'_, str> {
 
 impl Display for Cmd<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (key, value) in &self.env {
+            let value = if self.safe_env.contains(key) {
+                escape_arg(value)
+            } else {
+                Cow::Borrowed(REDACTED)
+            };
+            write!(f, "{key}={value} ")?;
+        }
         write!(f, "{}", self.command)?;
         for arg in &self.args {
             let arg = escape_arg(arg);
@@ -236,3 +398,62 @@ impl Display for Cmd<'_> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use assert2::{check, let_assert};
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn should_display_redacted_env() {
+        let mut cmd = Cmd::new("docker");
+        cmd.push_args(["compose", "up"]);
+        cmd.push_safe_env("DOCKER_CONFIG", "/ci/docker");
+        cmd.push_envs(IndexMap::from([
+            (String::from("USER"), String::from("admin")),
+            (String::from("PASSWORD"), String::from("s3cr3t")),
+        ]));
+        check!(
+            cmd.to_string() == "DOCKER_CONFIG=/ci/docker USER=*** PASSWORD=*** docker compose up"
+        );
+    }
+
+    #[rstest]
+    #[case::null("null\n")]
+    #[case::empty("")]
+    #[case::blank("  \n")]
+    fn should_parse_json_or_default(#[case] output: &str) {
+        let result = parse_json_or_default::<HashMap<String, String>>(output.as_bytes());
+        let_assert!(Ok(map) = result);
+        check!(map.is_empty());
+    }
+
+    #[test]
+    fn should_parse_json_or_default_value() {
+        let result = parse_json_or_default::<HashMap<String, u16>>(br#"{"a": 1}"#);
+        let_assert!(Ok(map) = result);
+        check!(map.get("a") == Some(&1));
+    }
+
+    #[test]
+    fn should_not_parse_invalid_json_or_default() {
+        let result = parse_json_or_default::<HashMap<String, u16>>(b"{");
+        check!(result.is_err());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn should_capture_exit_code_and_outputs() {
+        let mut cmd = Cmd::new("sh");
+        cmd.push_args(["-c", "echo out; echo err >&2; exit 3"]);
+        let result = cmd.exit_code_and_outputs().await;
+        let_assert!(Ok((exit_code, stdout, stderr)) = result);
+        check!(exit_code == 3);
+        check!(stdout == "out\n");
+        check!(stderr == "err\n");
+    }
+}
@@ -3,8 +3,11 @@ use std::ops::Deref;
 
 use tracing::{error, info};
 
-use crate::compose::ToRunnableComposeContainers;
-use crate::runner::Runner;
+use crate::compose::{
+    ComposeService, ResolvedComposeProject, ServiceStatus, ToRunnableComposeContainers,
+};
+use crate::runner::{Runner, RunnerError};
+use crate::{ContainerId, ExposedPort, Port, PortError};
 
 /// A running compose containers
 ///
@@ -12,16 +15,25 @@ use crate::runner::Runner;
 ///
 /// When it's dropped, by default it's stopping containers,
 /// but you can choose to keep alive those containers by calling [`ComposeContainers::detach`](Self::detach)
+///
+/// Note that the drop is a best effort, it runs a blocking `compose down` command,
+/// that could take a while for big stacks, and block the async runtime thread.
+/// In tests, prefer calling explicitly [`ComposeContainers::down`](Self::down).
 #[derive(Debug, Clone)]
 pub struct ComposeContainers<I>
 where
     I: ToRunnableComposeContainers,
 {
     pub(crate) runner: Runner,
-    pub(crate) name: String,
+    pub(crate) project: ResolvedComposeProject,
     pub(crate) images: I,
-    pub(crate) file: I::AsPath,
+    /// Keep the compose path alive (e.g. a temporary directory) while the containers are running
+    pub(crate) _file: I::AsPath,
+    /// The services port mappings, bound once the containers are started
+    pub(crate) port_mappings: Vec<(ComposeService, ExposedPort)>,
     pub(crate) detached: bool,
+    /// Already stopped with [`ComposeContainers::down`](Self::down)
+    pub(crate) stopped: bool,
 }
 
 impl<I> Deref for ComposeContainers<I>
@@ -39,6 +51,68 @@ impl<I> ComposeContainers<I>
 where
     I: ToRunnableComposeContainers,
 {
+    /// The current status of the services containers
+    ///
+    /// Useful to find which service is not running, or unhealthy.
+    ///
+    /// # Errors
+    ///
+    /// Could fail if we cannot execute the compose `ps` command
+    pub async fn service_states(&self) -> Result<Vec<ServiceStatus>, RunnerError> {
+        self.runner.compose_service_states(&self.project).await
+    }
+
+    /// The container id of a service
+    ///
+    /// Returns `None` if the service has no container.
+    /// The id could be used with [`Runner::exec_by_id`] to execute a command into the service container.
+    ///
+    /// # Errors
+    ///
+    /// Could fail if we cannot execute the compose `ps` command
+    pub async fn service_container_id(
+        &self,
+        service: &str,
+    ) -> Result<Option<ContainerId>, RunnerError> {
+        let states = self.service_states().await?;
+        let result = states
+            .into_iter()
+            .find(|state| state.service == service)
+            .map(|state| state.id);
+        Ok(result)
+    }
+
+    /// The resolved port mappings, as `(service, container port, host port)`
+    ///
+    /// It contains the port mappings declared by [`ToRunnableComposeContainers`],
+    /// in the declaration order.
+    ///
+    /// # Errors
+    ///
+    /// Could fail if a port is not bind
+    pub async fn port_mappings(&self) -> Result<Vec<(String, Port, Port)>, PortError> {
+        let mut result = Vec::with_capacity(self.port_mappings.len());
+        for (service, port_mapping) in &self.port_mappings {
+            let host_port = port_mapping.host_port().await?;
+            result.push((service.to_string(), port_mapping.container_port, host_port));
+        }
+        Ok(result)
+    }
+
+    /// Stop and remove the compose containers
+    ///
+    /// Unlike the drop, the `compose down` command does not block the async runtime.
+    /// The containers are stopped even if they are detached.
+    ///
+    /// # Errors
+    ///
+    /// Could fail if we cannot execute the compose `down` command
+    pub async fn down(mut self) -> Result<(), RunnerError> {
+        self.runner.compose_down(&self.project).await?;
+        self.stopped = true;
+        Ok(())
+    }
+
     /// Detach the container
     ///
     /// A detached container won't be stopped during the drop.
@@ -52,14 +126,17 @@ where
     I: ToRunnableComposeContainers,
 {
     fn drop(&mut self) {
-        let name = &self.name;
+        let name = &self.project.name;
+        if self.stopped {
+            return;
+        }
         if self.detached {
             info!(%name, "Detached compose containers {self} is NOT stopped");
             return;
         }
 
         info!(%name, "🚮 Stopping compose containers");
-        if let Err(err) = self.runner.compose_stop(&self.name, self.file.as_ref()) {
+        if let Err(err) = self.runner.compose_stop(&self.project) {
             error!(%name, "Fail to stop compose containers {self} because {err}");
         }
     }
@@ -70,6 +147,6 @@ where
     I: ToRunnableComposeContainers,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.name)
+        write!(f, "{}", self.project.name)
     }
 }
@@ -1,4 +1,5 @@
 use std::path::PathBuf;
+use std::time::Duration;
 
 use crate::runner::ContainerError;
 
@@ -24,10 +25,32 @@ pub enum ComposeError {
     #[error("Cannot launch compose containers {0:?}")]
     ComposeContainerCannotBeStarted(String),
 
+    /// Cannot stop compose containers
+    #[error("Cannot stop compose containers {0:?}")]
+    ComposeContainerCannotBeStopped(String),
+
     /// Custom health forbidden in compose
     #[error("Cannot use a custom health check with compose service {0}")]
     NoCustomHealthCheckInCompose(ComposeService),
 
+    /// Some services are not found in time
+    #[error("Compose services {services:?} are not found after {timeout:?}")]
+    MissingServices {
+        /// The services not found
+        services: Vec<ComposeService>,
+        /// The timeout
+        timeout: Duration,
+    },
+
+    /// Some services are not healthy in time
+    #[error("Compose services {services:?} are not healthy after {timeout:?}")]
+    UnhealthyServices {
+        /// The services not healthy
+        services: Vec<String>,
+        /// The timeout
+        timeout: Duration,
+    },
+
     /// A temporary directory error
     #[error(transparent)]
     TempDirError(#[from] TempDirError),
@@ -52,6 +75,10 @@ pub enum ComposeError {
     /// Missing compose version
     #[error("Missing compose version")]
     MissingComposeVersion,
+
+    /// No available host port
+    #[error("Cannot find an available host port because {0}")]
+    NoAvailablePort(std::io::Error),
 }
 
 /// A temporary directory error
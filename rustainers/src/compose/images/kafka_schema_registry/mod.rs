@@ -1,5 +1,6 @@
 use std::fmt::{self, Display};
 use std::fs::Permissions;
+use std::net::{Ipv4Addr, TcpListener};
 use std::os::unix::prelude::PermissionsExt;
 
 use crate::compose::{
@@ -36,7 +37,10 @@ impl KafkaSchemaRegistry {
     ///
     /// Fail if we cannot create the temporary directory
     pub async fn build_single_kraft() -> Result<Self, ComposeError> {
-        let kafka_port = ExposedPort::new(KAFKA_PORT);
+        // The advertised listener should match the host port,
+        // so the port is chosen before starting, and provided with the `.env` file
+        let kafka_host_port = available_port()?;
+        let kafka_port = ExposedPort::fixed(KAFKA_PORT, kafka_host_port);
         let schema_registry_port = ExposedPort::new(SCHEMA_REGISTRY_PORT);
         let temp_dir = TemporaryDirectory::with_files(
             "kafka_schema_registry",
@@ -46,6 +50,10 @@ impl KafkaSchemaRegistry {
                     .with_path("docker-compose.yaml")
                     .with_content(include_bytes!("./docker-compose.kraft.yaml"))
                     .build(),
+                TemporaryFile::builder()
+                    .with_path(".env")
+                    .with_content(format!("KAFKA_HOST_PORT={kafka_host_port}\n"))
+                    .build(),
                 TemporaryFile::builder()
                     .with_path("kafka_update_run.sh")
                     .with_content(include_bytes!("./kafka_update_run.sh"))
@@ -87,6 +95,18 @@ impl KafkaSchemaRegistry {
     }
 }
 
+/// Find an available host port
+///
+/// The port is released before starting the containers,
+/// so another process could take it in the meantime.
+fn available_port() -> Result<Port, ComposeError> {
+    let port = TcpListener::bind((Ipv4Addr::LOCALHOST, 0))
+        .and_then(|listener| listener.local_addr())
+        .map_err(ComposeError::NoAvailablePort)?
+        .port();
+    Ok(Port(port))
+}
+
 impl ToRunnableComposeContainers for KafkaSchemaRegistry {
     type AsPath = TemporaryDirectory;
 
@@ -123,5 +143,12 @@ mod tests {
 
         assert!(dir.join("docker-compose.yaml").exists());
         assert!(dir.join("kafka_update_run.sh").exists());
+
+        // The advertised listener uses the host port
+        let host_port = image.kafka_port.host_port().await.expect("host port");
+        let env = std::fs::read_to_string(dir.join(".env")).expect(".env file");
+        assert_eq!(env, format!("KAFKA_HOST_PORT={host_port}\n"));
+        let broker = image.broker_address().await.expect("broker address");
+        assert_eq!(broker, format!("127.0.0.1:{host_port}"));
     }
 }
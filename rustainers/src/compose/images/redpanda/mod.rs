@@ -4,7 +4,7 @@ use crate::compose::{
     ComposeError, RunnableComposeContainers, RunnableComposeContainersBuilder, TemporaryDirectory,
     TemporaryFile, ToRunnableComposeContainers,
 };
-use crate::{ExposedPort, Port, PortError};
+use crate::{ExposedPort, Port, PortError, WaitStrategy};
 
 const REDPANDA_SERVICE: &str = "redpanda-0";
 const REDPANDA_PROXY_PORT: Port = Port(18082);
@@ -18,6 +18,7 @@ const REDPANDA_CONSOLE_PORT: Port = Port(8080);
 
 /// A docker compose with a single node Redpanda
 #[derive(Debug)]
+#[allow(clippy::struct_field_names)]
 pub struct Redpanda {
     temp_dir: TemporaryDirectory,
     schema_registry_port: ExposedPort,
@@ -90,6 +91,42 @@ impl Redpanda {
 
         Ok(addr)
     }
+
+    /// The HTTP proxy (pandaproxy) endpoint
+    ///
+    /// # Errors
+    ///
+    /// Fail if we cannot retrieve the HTTP proxy host port
+    pub async fn proxy_endpoint(&self) -> Result<String, PortError> {
+        let port = self.redpanda_proxy_port.host_port().await?;
+        let addr = format!("http://127.0.0.1:{port}");
+
+        Ok(addr)
+    }
+
+    /// The admin API endpoint
+    ///
+    /// # Errors
+    ///
+    /// Fail if we cannot retrieve the admin API host port
+    pub async fn admin_endpoint(&self) -> Result<String, PortError> {
+        let port = self.redpanda_admin_port.host_port().await?;
+        let addr = format!("http://127.0.0.1:{port}");
+
+        Ok(addr)
+    }
+
+    /// The Redpanda console endpoint
+    ///
+    /// # Errors
+    ///
+    /// Fail if we cannot retrieve the console host port
+    pub async fn console_endpoint(&self) -> Result<String, PortError> {
+        let port = self.redpanda_console_port.host_port().await?;
+        let addr = format!("http://127.0.0.1:{port}");
+
+        Ok(addr)
+    }
 }
 
 impl ToRunnableComposeContainers for Redpanda {
@@ -108,11 +145,18 @@ impl ToRunnableComposeContainers for Redpanda {
                 (REDPANDA_SERVICE, self.redpanda_admin_port.clone()),
                 (REDPANDA_CONSOLE_SERVICE, self.redpanda_console_port.clone()),
             ])
-            // TODO
-            // .with_wait_strategies([
-            // (REDPANDA_SERVICE, WaitStrategy::HealthCheck),
-            // (REDPANDA_CONSOLE_SERVICE, WaitStrategy::HealthCheck),
-            // ])
+            .with_wait_strategies([
+                (REDPANDA_SERVICE, WaitStrategy::HealthCheck),
+                (
+                    REDPANDA_CONSOLE_SERVICE,
+                    WaitStrategy::HttpSuccess {
+                        https: false,
+                        require_valid_certs: false,
+                        path: String::from("/admin/health"),
+                        container_port: REDPANDA_CONSOLE_PORT,
+                    },
+                ),
+            ])
             .build()
     }
 }
@@ -1,32 +1,85 @@
-use std::ffi::OsStr;
 use std::path::Path;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use async_trait::async_trait;
+use futures_util::stream::{self, StreamExt};
 use tracing::{info, warn};
 
 use crate::cmd::Cmd;
 use crate::runner::InnerRunner;
-use crate::{ContainerId, ExposedPort, WaitStrategy};
+use crate::{ContainerHealth, ContainerId, ContainerStatus, ExposedPort, WaitStrategy};
 
-use super::{ComposeError, ComposeRunOption, ComposeService, ComposeServiceState, Services};
+use super::{
+    ComposeError, ComposeRunOption, ComposeService, ComposeServiceState, ResolvedComposeProject,
+    ServiceStatus, Services,
+};
+
+/// The maximum number of concurrent waits, or port lookups
+const MAX_CONCURRENT_TASKS: usize = 8;
+
+/// The services with a health check that are not (yet) healthy
+fn not_healthy_services(states: &[ServiceStatus]) -> Vec<String> {
+    states
+        .iter()
+        .filter(|state| {
+            matches!(
+                state.health,
+                ContainerHealth::Starting | ContainerHealth::Unhealthy
+            )
+        })
+        .map(|state| state.service.clone())
+        .collect()
+}
+
+/// Returns the first error, following the services order, other errors are logged
+fn first_error(results: Vec<Result<(), ComposeError>>) -> Result<(), ComposeError> {
+    let mut errors = results.into_iter().filter_map(Result::err);
+    let Some(first) = errors.next() else {
+        return Ok(());
+    };
+    for err in errors {
+        warn!(%err, "Another compose service failure");
+    }
+    Err(first)
+}
 
 #[async_trait]
 pub(crate) trait InnerComposeRunner: InnerRunner {
     fn compose_command(&self) -> Result<Cmd<'static>, ComposeError>;
 
+    /// The flag to set the project name
+    ///
+    /// All the providers support `-p`, the long form is preferred when it's available
+    fn project_name_flag(&self) -> &'static str {
+        "--project-name"
+    }
+
+    /// The compose command configured for the project (directory, file, and name)
+    fn project_compose_command<'a>(
+        &self,
+        project: &'a ResolvedComposeProject,
+    ) -> Result<Cmd<'a>, ComposeError> {
+        let mut cmd = self.compose_command()?;
+        project.configure(&mut cmd, self.project_name_flag());
+        Ok(cmd)
+    }
+
+    /// Start the compose containers, returns the project, and if the containers are reused
     #[tracing::instrument(skip(self), fields(runner = %self))]
     async fn start_compose(
         &self,
-        dir: &Path,
+        path: &Path,
         wait_strategies: &[(ComposeService, WaitStrategy)],
         port_mappings: &mut [(ComposeService, ExposedPort)],
         options: ComposeRunOption,
-    ) -> Result<String, ComposeError> {
-        let Some(name) = dir.file_name().and_then(OsStr::to_str).map(str::to_string) else {
-            return Err(ComposeError::BadComposeFile(dir.to_path_buf()))?;
-        };
-        self.compose_up(&name, dir, &options).await?;
+    ) -> Result<(ResolvedComposeProject, bool), ComposeError> {
+        let project = ResolvedComposeProject::new(path, &options)?;
+        let reused = options.reuse && self.is_compose_running(&project).await?;
+        if reused {
+            info!(name = %project.name, "♻️ Reuse running compose containers");
+        } else {
+            self.compose_up(&project, &options).await?;
+        }
 
         // Find required services
         let required_services = wait_strategies
@@ -35,15 +88,17 @@ pub(crate) trait InnerComposeRunner: InnerRunner {
             .collect::<Vec<_>>();
         let services = self
             .find_required_services(
-                &name,
+                &project,
                 &required_services,
                 options.wait_services_interval,
-                dir,
+                options.wait_services_timeout,
             )
             .await?;
 
-        // Wait
+        // Wait, concurrently
         let interval = options.wait_interval;
+        let log_buffer_size = options.log_buffer_size;
+        let mut waits = Vec::with_capacity(wait_strategies.len());
         for (service, wait) in wait_strategies {
             let Some(id) = services.get(service) else {
                 warn!(
@@ -54,10 +109,20 @@ pub(crate) trait InnerComposeRunner: InnerRunner {
                 );
                 continue;
             };
-            self.wait_service_ready(service, id, wait, interval).await?;
+            waits.push(self.wait_service_ready(service, id, wait, interval, log_buffer_size));
+        }
+        let results = stream::iter(waits)
+            .buffered(MAX_CONCURRENT_TASKS)
+            .collect::<Vec<_>>()
+            .await;
+        first_error(results)?;
+        if options.wait_all_healthy {
+            self.wait_all_healthy(&project, interval, options.wait_all_healthy_timeout)
+                .await?;
         }
 
-        // Port mapping
+        // Port mapping, concurrently
+        let mut bindings = Vec::with_capacity(port_mappings.len());
         for (service, mapping) in port_mappings {
             let Some(id) = services.get(service) else {
                 warn!(
@@ -68,11 +133,41 @@ pub(crate) trait InnerComposeRunner: InnerRunner {
                 );
                 continue;
             };
-            let port = self.port(id, mapping.container_port).await?;
-            mapping.bind_port(port).await;
+            bindings.push(self.bind_service_port(id, mapping));
         }
+        let results = stream::iter(bindings)
+            .buffered(MAX_CONCURRENT_TASKS)
+            .collect::<Vec<_>>()
+            .await;
+        first_error(results)?;
+
+        Ok((project, reused))
+    }
+
+    /// Bind the exposed port to the service container host port
+    async fn bind_service_port(
+        &self,
+        id: ContainerId,
+        mapping: &mut ExposedPort,
+    ) -> Result<(), ComposeError> {
+        let port = self
+            .protocol_port(id, mapping.container_port, mapping.protocol)
+            .await?;
+        mapping.bind_port(port).await;
+        Ok(())
+    }
 
-        Ok(name)
+    /// Check if all the project containers are running
+    async fn is_compose_running(
+        &self,
+        project: &ResolvedComposeProject,
+    ) -> Result<bool, ComposeError> {
+        let states = self.compose_ps(project).await?;
+        let result = !states.is_empty()
+            && states
+                .iter()
+                .all(|state| state.state == ContainerStatus::Running);
+        Ok(result)
     }
 
     #[tracing::instrument(level = "debug", skip(self), fields(runner = %self))]
@@ -82,26 +177,48 @@ pub(crate) trait InnerComposeRunner: InnerRunner {
         id: ContainerId,
         wait_condition: &WaitStrategy,
         interval: Duration,
+        log_buffer_size: usize,
     ) -> Result<(), ComposeError> {
-        self.wait_ready(id, wait_condition, interval).await?;
+        self.wait_ready(id, wait_condition, interval, log_buffer_size)
+            .await?;
         Ok(())
     }
 
+    /// Wait until all the services with a health check are healthy
+    #[tracing::instrument(level = "debug", skip(self), fields(runner = %self))]
+    async fn wait_all_healthy(
+        &self,
+        project: &ResolvedComposeProject,
+        interval: Duration,
+        timeout: Duration,
+    ) -> Result<(), ComposeError> {
+        let start = Instant::now();
+        loop {
+            let states = self.compose_ps(project).await?;
+            let services = not_healthy_services(&states);
+            if services.is_empty() {
+                return Ok(());
+            }
+            if start.elapsed() >= timeout {
+                return Err(ComposeError::UnhealthyServices { services, timeout });
+            }
+            info!(?services, "⏳ Waiting for the services to be healthy");
+            tokio::time::sleep(interval).await;
+        }
+    }
+
     #[tracing::instrument(level = "debug", skip(self), fields(runner = %self))]
     async fn compose_up(
         &self,
-        name: &str,
-        dir: &Path,
+        project: &ResolvedComposeProject,
         options: &ComposeRunOption,
     ) -> Result<(), ComposeError> {
+        let name = &project.name;
+        let dir = &project.dir;
         info!(%name, ?dir, "🚀 Launching compose container");
-        let mut cmd = self.compose_command()?;
-        cmd.with_dir(dir);
+        let mut cmd = self.project_compose_command(project)?;
         cmd.push_args(["up", "--detach"]);
-        if let Some(file) = options.compose_file.as_ref().and_then(|it| it.to_str()) {
-            cmd.push_args(["--file", file]);
-        }
-        cmd.set_env(options.env.clone());
+        cmd.push_envs(options.env.clone());
 
         let cmd_err = cmd.clone();
         let status = cmd.status().await?;
@@ -114,15 +231,52 @@ pub(crate) trait InnerComposeRunner: InnerRunner {
         }
     }
 
+    #[tracing::instrument(level = "debug", skip(self), fields(runner = %self))]
+    async fn compose_down(&self, project: &ResolvedComposeProject) -> Result<(), ComposeError> {
+        project.check_exists()?;
+        let mut cmd = self.project_compose_command(project)?;
+        cmd.push_arg("down");
+
+        let cmd_err = cmd.clone();
+        let status = cmd.status().await?;
+        if status.success() {
+            Ok(())
+        } else {
+            Err(ComposeError::ComposeContainerCannotBeStopped(
+                cmd_err.to_string(),
+            ))
+        }
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), fields(runner = %self))]
+    async fn compose_config(
+        &self,
+        project: &ResolvedComposeProject,
+        options: &ComposeRunOption,
+    ) -> Result<String, ComposeError> {
+        let mut cmd = self.project_compose_command(project)?;
+        cmd.push_arg("config");
+        cmd.push_envs(options.env.clone());
+        let result = cmd.result().await?;
+        Ok(result)
+    }
+
+    async fn compose_ps(
+        &self,
+        project: &ResolvedComposeProject,
+    ) -> Result<Vec<ServiceStatus>, ComposeError> {
+        let mut cmd = self.project_compose_command(project)?;
+        cmd.push_args(["ps", "--all", "--no-trunc", "--format", "json"]);
+        let states = cmd.json_list::<ComposeServiceState>().await?;
+        let result = states.into_iter().map(ServiceStatus::from).collect();
+        Ok(result)
+    }
+
     async fn compose_look_up_services(
         &self,
-        _name: &str,
-        path: &Path,
+        project: &ResolvedComposeProject,
     ) -> Result<Services, ComposeError> {
-        let mut cmd = self.compose_command()?;
-        cmd.with_dir(path);
-        cmd.push_args(["ps", "--all", "--no-trunc", "--format", "json"]);
-        let states = cmd.json_stream::<ComposeServiceState>().await?;
+        let states = self.compose_ps(project).await?;
         let result = Services::from(states);
         Ok(result)
     }
@@ -130,17 +284,86 @@ pub(crate) trait InnerComposeRunner: InnerRunner {
     #[tracing::instrument(skip(self), fields(runner = %self))]
     async fn find_required_services(
         &self,
-        name: &str,
+        project: &ResolvedComposeProject,
         required_services: &[ComposeService],
         interval: Duration,
-        path: &Path,
+        timeout: Duration,
     ) -> Result<Services, ComposeError> {
+        let start = Instant::now();
         loop {
-            let result = self.compose_look_up_services(name, path).await?;
-            if result.contains_all(required_services) {
-                return Ok(result);
+            let timed_out = start.elapsed() >= timeout;
+            match self.compose_look_up_services(project).await {
+                Ok(result) if result.contains_all(required_services) => return Ok(result),
+                Ok(result) if timed_out => {
+                    let services = required_services
+                        .iter()
+                        .filter(|service| !result.contains(service))
+                        .cloned()
+                        .collect();
+                    return Err(ComposeError::MissingServices { services, timeout });
+                }
+                Ok(_) => {}
+                // Command could fail transiently while the services are registering
+                Err(ComposeError::CommandError(source)) if !timed_out => {
+                    warn!(%source, "Fail to look up compose services, retrying");
+                }
+                Err(err) => return Err(err),
             }
             tokio::time::sleep(interval).await;
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+
+    use super::*;
+
+    #[test]
+    fn should_report_first_error_in_order() {
+        let results = vec![
+            Ok(()),
+            Err(ComposeError::MissingComposeVersion),
+            Err(ComposeError::UnsupportedComposeCommand(String::from(
+                "plop",
+            ))),
+        ];
+        let_assert!(Err(err) = first_error(results));
+        check!(matches!(err, ComposeError::MissingComposeVersion));
+
+        check!(first_error(vec![Ok(()), Ok(())]).is_ok());
+    }
+
+    fn service(service: &str, health: ContainerHealth) -> ServiceStatus {
+        let id = "8b9c0f3a4e1d2c5b6a7f8e9d0c1b2a3f4e5d6c7b8a9f0e1d2c3b4a5f6e7d8c9b"
+            .parse::<ContainerId>()
+            .expect("container id");
+        ServiceStatus {
+            service: service.to_string(),
+            name: format!("project-{service}-1"),
+            id,
+            state: ContainerStatus::Running,
+            health,
+            exit_code: None,
+        }
+    }
+
+    #[test]
+    fn should_find_not_healthy_services() {
+        let states = [
+            service("db", ContainerHealth::Healthy),
+            service("web", ContainerHealth::Starting),
+            service("proxy", ContainerHealth::Unknown),
+            service("worker", ContainerHealth::Unhealthy),
+        ];
+        let result = not_healthy_services(&states);
+        check!(result == ["web", "worker"]);
+
+        let states = [
+            service("db", ContainerHealth::Healthy),
+            service("proxy", ContainerHealth::Unknown),
+        ];
+        check!(not_healthy_services(&states).is_empty());
+    }
+}
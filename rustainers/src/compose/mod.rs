@@ -11,7 +11,8 @@ mod service;
 pub(crate) use self::service::*;
 
 mod service_state;
-pub(crate) use self::service_state::*;
+pub(crate) use self::service_state::ComposeServiceState;
+pub use self::service_state::ServiceStatus;
 
 mod options;
 pub use self::options::*;
@@ -19,6 +20,12 @@ pub use self::options::*;
 mod runnable;
 pub use self::runnable::*;
 
+mod project;
+pub use self::project::*;
+
+mod resolved;
+pub(crate) use self::resolved::*;
+
 mod inner;
 pub(crate) use self::inner::InnerComposeRunner;
 
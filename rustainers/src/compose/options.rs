@@ -1,18 +1,35 @@
+use std::fmt::{self, Debug};
 use std::path::PathBuf;
 use std::time::Duration;
 
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
 use typed_builder::TypedBuilder;
 
+use crate::cmd::REDACTED;
+use crate::io::DEFAULT_LOG_BUFFER_SIZE;
+
 /// Run options
 ///
 /// Available options:
 ///
 /// * `wait_interval`: wait until re-check a container state (default 1s)
 /// * `wait_services_interval`: wait until re-check that all services starting (default 96ms)
+/// * `wait_services_timeout`: the maximum time to wait all services are starting (default 2min),
+///   the transient errors of the services look up are retried until this timeout
+/// * `log_buffer_size`: the number of log lines buffered while waiting for a log line (default 256)
 /// * `env`: a map of environment variables used when launch the container
-/// * `compose-file`: if you need to use another compose file (`--file` option)
-#[derive(Debug, Clone, TypedBuilder)]
+/// * `sensitive_env`: the environment variables with a secret value, masked in the options debug output (the logged commands never show the `env` values)
+/// * `compose-file`: if you need to use another compose file (`--file` option),
+///   a relative path is resolved from the compose path
+/// * `project_name`: set the project name (`--project-name` option),
+///   by default it's the name of the directory containing the compose file
+/// * `reuse`: reuse the compose containers if they are already running (default false),
+///   the reused containers are not stopped during the drop.
+///   It requires a stable project name, e.g. an explicit `project_name`
+/// * `wait_all_healthy`: wait until all the services with a health check are healthy (default false),
+///   in addition to the wait strategies
+/// * `wait_all_healthy_timeout`: the maximum time to wait all the services are healthy (default 2min)
+#[derive(Clone, TypedBuilder)]
 #[builder(field_defaults(default, setter(prefix = "with_")))]
 pub struct ComposeRunOption {
     /// Wait interval for service health check
@@ -23,13 +40,44 @@ pub struct ComposeRunOption {
     #[builder(default = Duration::from_millis(96))]
     pub(crate) wait_services_interval: Duration,
 
+    /// The maximum time to wait for the services to exist
+    #[builder(default = Duration::from_secs(120))]
+    pub(crate) wait_services_timeout: Duration,
+
+    /// The capacity of the channel used to watch the services logs
+    ///
+    /// A zero capacity is not supported by the channel, so it's raised to 1
+    #[builder(default = DEFAULT_LOG_BUFFER_SIZE, setter(transform = |size: usize| size.max(1)))]
+    pub(crate) log_buffer_size: usize,
+
     /// The environment variables
     #[builder(setter(transform = |args: impl IntoIterator<Item = (impl Into<String>, impl Into<String>)>| args.into_iter().map(|(key, value)| (key.into(), value.into())).collect()))]
     pub(crate) env: IndexMap<String, String>,
 
+    /// The sensitive environment variables (keys)
+    #[builder(setter(transform = |keys: impl IntoIterator<Item = impl Into<String>>| keys.into_iter().map(Into::into).collect()))]
+    pub(crate) sensitive_env: IndexSet<String>,
+
     /// The compose file
-    #[builder(setter(strip_option))]
+    #[builder(setter(into, strip_option))]
     pub(crate) compose_file: Option<PathBuf>,
+
+    /// The project name
+    #[builder(setter(into, strip_option))]
+    pub(crate) project_name: Option<String>,
+
+    /// Reuse the running compose containers
+    pub(crate) reuse: bool,
+
+    /// Wait until all the services with a health check are healthy
+    ///
+    /// The services without health check are ignored,
+    /// it avoids declaring a [`WaitStrategy::HealthCheck`](crate::WaitStrategy::HealthCheck) per service.
+    pub(crate) wait_all_healthy: bool,
+
+    /// The maximum time to wait all the services are healthy
+    #[builder(default = Duration::from_secs(120))]
+    pub(crate) wait_all_healthy_timeout: Duration,
 }
 
 impl Default for ComposeRunOption {
@@ -37,3 +85,57 @@ impl Default for ComposeRunOption {
         ComposeRunOption::builder().build()
     }
 }
+
+impl Debug for ComposeRunOption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let env = self
+            .env
+            .iter()
+            .map(|(key, value)| {
+                let value = if self.sensitive_env.contains(key) {
+                    REDACTED
+                } else {
+                    value.as_str()
+                };
+                (key, value)
+            })
+            .collect::<IndexMap<_, _>>();
+        f.debug_struct("ComposeRunOption")
+            .field("wait_interval", &self.wait_interval)
+            .field("wait_services_interval", &self.wait_services_interval)
+            .field("wait_services_timeout", &self.wait_services_timeout)
+            .field("log_buffer_size", &self.log_buffer_size)
+            .field("env", &env)
+            .field("sensitive_env", &self.sensitive_env)
+            .field("compose_file", &self.compose_file)
+            .field("project_name", &self.project_name)
+            .field("reuse", &self.reuse)
+            .field("wait_all_healthy", &self.wait_all_healthy)
+            .field("wait_all_healthy_timeout", &self.wait_all_healthy_timeout)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::check;
+
+    use super::*;
+
+    #[test]
+    fn should_redact_sensitive_env() {
+        let options = ComposeRunOption::builder()
+            .with_env([("USER", "admin"), ("PASSWORD", "s3cr3t")])
+            .with_sensitive_env(["PASSWORD"])
+            .build();
+        let debug = format!("{options:?}");
+        check!(debug.contains("\"admin\""));
+        check!(!debug.contains("s3cr3t"));
+    }
+
+    #[test]
+    fn should_raise_zero_log_buffer_size() {
+        let options = ComposeRunOption::builder().with_log_buffer_size(0).build();
+        check!(options.log_buffer_size == 1);
+    }
+}
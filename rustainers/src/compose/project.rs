@@ -0,0 +1,85 @@
+use std::path::PathBuf;
+
+use typed_builder::TypedBuilder;
+
+use super::{
+    ComposeService, RunnableComposeContainers, RunnableComposeContainersBuilder,
+    ToRunnableComposeContainers,
+};
+use crate::{ExposedPort, WaitStrategy};
+
+/// A compose project from an existing directory
+///
+/// The path could be the directory containing the compose file,
+/// or directly the compose file.
+/// Unlike a [`crate::compose::TemporaryDirectory`], the directory is never removed.
+///
+/// ```rust, no_run
+/// # async fn run() -> anyhow::Result<()> {
+/// use rustainers::compose::ComposeProject;
+/// use rustainers::runner::Runner;
+/// use rustainers::{ExposedPort, WaitStrategy};
+///
+/// let runner = Runner::auto()?;
+/// let project = ComposeProject::builder()
+///     .with_path("./docker")
+///     .with_wait_strategies([("db", WaitStrategy::HealthCheck)])
+///     .with_port_mappings([("db", ExposedPort::new(5432))])
+///     .build();
+/// let containers = runner.compose_start(project).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, TypedBuilder)]
+#[builder(field_defaults(setter(prefix = "with_")))]
+#[non_exhaustive]
+pub struct ComposeProject {
+    #[builder(setter(into))]
+    path: PathBuf,
+
+    /// The wait condition
+    #[builder(default, setter(transform = |args: impl IntoIterator<Item = (impl Into<ComposeService>, impl Into<WaitStrategy>)>| args.into_iter().map(|(key, value)| (key.into(), value.into())).collect()))]
+    wait_strategies: Vec<(ComposeService, WaitStrategy)>,
+
+    /// The services port mapping
+    #[builder(default, setter(transform = |args: impl IntoIterator<Item = (impl Into<ComposeService>, ExposedPort)>| args.into_iter().map(|(key, value)| (key.into(), value)).collect()))]
+    port_mappings: Vec<(ComposeService, ExposedPort)>,
+}
+
+impl ToRunnableComposeContainers for ComposeProject {
+    type AsPath = PathBuf;
+
+    fn to_runnable(
+        &self,
+        builder: RunnableComposeContainersBuilder<Self::AsPath>,
+    ) -> RunnableComposeContainers<Self::AsPath> {
+        builder
+            .with_compose_path(self.path.clone())
+            .with_wait_strategies(self.wait_strategies.clone())
+            .with_port_mappings(self.port_mappings.clone())
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+
+    use super::*;
+
+    #[test]
+    fn should_build_runnable() {
+        let project = ComposeProject::builder()
+            .with_path("/tmp/project")
+            .with_wait_strategies([("web", WaitStrategy::HealthCheck)])
+            .with_port_mappings([("web", ExposedPort::new(80))])
+            .build();
+
+        let runnable = project.to_runnable(RunnableComposeContainers::builder());
+        check!(runnable.compose_path == PathBuf::from("/tmp/project"));
+        let_assert!([(service, WaitStrategy::HealthCheck)] = runnable.wait_strategies.as_slice());
+        check!(service.to_string() == "web");
+        let_assert!([(service, _)] = runnable.port_mappings.as_slice());
+        check!(service.to_string() == "web");
+    }
+}
@@ -0,0 +1,127 @@
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+
+use crate::cmd::Cmd;
+
+use super::{ComposeError, ComposeRunOption};
+
+/// A resolved compose project, with its name and files
+///
+/// The compose path could be the directory containing the compose file,
+/// or directly the compose file.
+///
+/// The project name is the explicit name provided with the options,
+/// otherwise it's the name of the directory containing the compose file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ResolvedComposeProject {
+    pub(crate) name: String,
+    pub(crate) dir: PathBuf,
+    pub(crate) file: Option<PathBuf>,
+    pub(crate) explicit_name: bool,
+}
+
+impl ResolvedComposeProject {
+    pub(crate) fn new(path: &Path, options: &ComposeRunOption) -> Result<Self, ComposeError> {
+        let (dir, file) = if path.is_file() {
+            let Some(dir) = path.parent() else {
+                return Err(ComposeError::BadComposeFile(path.to_path_buf()));
+            };
+            (dir.to_path_buf(), Some(path.to_path_buf()))
+        } else {
+            let file = options.compose_file.as_ref().map(|file| path.join(file));
+            (path.to_path_buf(), file)
+        };
+
+        let project_dir = file.as_deref().and_then(Path::parent).unwrap_or(&dir);
+        let name = if let Some(name) = &options.project_name {
+            name.clone()
+        } else if let Some(name) = project_dir.file_name().and_then(OsStr::to_str) {
+            name.to_string()
+        } else {
+            return Err(ComposeError::BadComposeFile(path.to_path_buf()));
+        };
+
+        Ok(Self {
+            name,
+            dir,
+            file,
+            explicit_name: options.project_name.is_some(),
+        })
+    }
+
+    /// Check the project files still exist
+    pub(crate) fn check_exists(&self) -> Result<(), ComposeError> {
+        let path = self.file.as_ref().unwrap_or(&self.dir);
+        if path.exists() {
+            Ok(())
+        } else {
+            Err(ComposeError::ComposeFileMissing(path.clone()))
+        }
+    }
+
+    /// Configure the compose command with the project directory, file, and name
+    ///
+    /// The project name flag depends on the compose provider, e.g. `--project-name`, or `-p`
+    pub(crate) fn configure<'a>(&'a self, cmd: &mut Cmd<'a>, project_name_flag: &'static str) {
+        cmd.with_dir(&self.dir);
+        if let Some(file) = &self.file {
+            cmd.push_arg("--file");
+            cmd.push_arg(file.to_string_lossy());
+        }
+        if self.explicit_name {
+            cmd.push_args([project_name_flag, &self.name]);
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::ignored_unit_patterns)]
+mod tests {
+    use assert2::{check, let_assert};
+
+    use super::*;
+    use crate::compose::TemporaryDirectory;
+
+    #[tokio::test]
+    async fn should_use_dir_name_as_project_name() {
+        let dir = TemporaryDirectory::new("project")
+            .await
+            .expect("temp. dir.");
+        let options = ComposeRunOption::default();
+
+        let result = ResolvedComposeProject::new(dir.as_ref(), &options);
+        let_assert!(Ok(project) = result);
+        let_assert!(Some(expected) = dir.as_ref().file_name().and_then(OsStr::to_str));
+        check!(project.name == expected);
+        check!(project.dir == dir.as_ref());
+        check!(project.file.is_none());
+    }
+
+    #[test]
+    fn should_use_compose_file_parent_as_project_name() {
+        let options = ComposeRunOption::builder()
+            .with_compose_file("/somewhere/my-project/docker-compose.yaml")
+            .build();
+
+        let result = ResolvedComposeProject::new(Path::new("/tmp/plop"), &options);
+        let_assert!(Ok(project) = result);
+        check!(project.name == "my-project");
+        check!(project.dir == Path::new("/tmp/plop"));
+        let_assert!(Some(file) = project.file);
+        check!(file == Path::new("/somewhere/my-project/docker-compose.yaml"));
+    }
+
+    #[test]
+    fn should_use_explicit_project_name() {
+        let options = ComposeRunOption::builder()
+            .with_compose_file("docker-compose.yaml")
+            .with_project_name("plop")
+            .build();
+
+        let result = ResolvedComposeProject::new(Path::new("/tmp/my-project"), &options);
+        let_assert!(Ok(project) = result);
+        check!(project.name == "plop");
+        let_assert!(Some(file) = project.file);
+        check!(file == Path::new("/tmp/my-project/docker-compose.yaml"));
+    }
+}
@@ -16,7 +16,27 @@ pub struct RunnableComposeContainers<P> {
     pub(crate) compose_path: P,
 
     /// The wait condition
-    #[builder(default, setter(transform = |args: impl IntoIterator<Item = (impl Into<ComposeService>, impl Into<WaitStrategy>)>| args.into_iter().map(|(key, value)| (key.into(), value.into())).collect()))]
+    ///
+    /// Each strategy is applied to the container of the service,
+    /// including the log based strategies, e.g. [`WaitStrategy::stdout_contains`]
+    #[builder(via_mutators(init = Vec::new()), mutators(
+        /// Add some wait strategies, could be called multiple times
+        pub fn with_wait_strategies(&mut self, args: impl IntoIterator<Item = (impl Into<ComposeService>, impl Into<WaitStrategy>)>) {
+            self.wait_strategies.extend(args.into_iter().map(|(key, value)| (key.into(), value.into())));
+        }
+
+        /// Wait until the service standard output contains the pattern,
+        /// see [`WaitStrategy::stdout_contains`]
+        pub fn wait_for_service_log(&mut self, service: impl Into<ComposeService>, pattern: impl Into<String>) {
+            self.wait_strategies.push((service.into(), WaitStrategy::stdout_contains(pattern)));
+        }
+
+        /// Wait until the service standard error contains the pattern,
+        /// see [`WaitStrategy::stderr_contains`]
+        pub fn wait_for_service_stderr_log(&mut self, service: impl Into<ComposeService>, pattern: impl Into<String>) {
+            self.wait_strategies.push((service.into(), WaitStrategy::stderr_contains(pattern)));
+        }
+    ))]
     pub(crate) wait_strategies: Vec<(ComposeService, WaitStrategy)>,
 
     /// The services port mapping
@@ -39,3 +59,47 @@ pub trait ToRunnableComposeContainers {
         builder: RunnableComposeContainersBuilder<Self::AsPath>,
     ) -> RunnableComposeContainers<Self::AsPath>;
 }
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+
+    use super::*;
+    use crate::io::StdIoKind;
+    use crate::LogMatcher;
+
+    #[test]
+    fn should_wait_for_service_log() {
+        let runnable = RunnableComposeContainers::builder()
+            .with_compose_path("docker-compose.yaml")
+            .with_wait_strategies([("db", WaitStrategy::HealthCheck)])
+            .wait_for_service_log("web", "Listening on")
+            .wait_for_service_stderr_log("worker", "ready")
+            .build();
+
+        let_assert!(
+            [
+                (db, WaitStrategy::HealthCheck),
+                (web, web_strategy),
+                (worker, worker_strategy)
+            ] = runnable.wait_strategies.as_slice()
+        );
+        check!(db.as_ref() == "db");
+        check!(web.as_ref() == "web");
+        let_assert!(
+            WaitStrategy::LogMatch {
+                io: StdIoKind::Out,
+                matcher: LogMatcher::Contains(pattern),
+            } = web_strategy
+        );
+        check!(pattern == "Listening on");
+        check!(worker.as_ref() == "worker");
+        let_assert!(
+            WaitStrategy::LogMatch {
+                io: StdIoKind::Err,
+                matcher: LogMatcher::Contains(pattern),
+            } = worker_strategy
+        );
+        check!(pattern == "ready");
+    }
+}
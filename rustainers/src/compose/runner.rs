@@ -2,11 +2,11 @@ use std::path::Path;
 
 use tracing::{info, warn};
 
-use crate::runner::{Runner, RunnerError};
+use crate::runner::{Feature, Runner, RunnerError};
 
 use super::{
-    ComposeContainers, ComposeError, ComposeRunOption, InnerComposeRunner,
-    RunnableComposeContainers, ToRunnableComposeContainers,
+    ComposeContainers, ComposeError, ComposeRunOption, InnerComposeRunner, ResolvedComposeProject,
+    RunnableComposeContainers, ServiceStatus, ToRunnableComposeContainers,
 };
 
 impl Runner {
@@ -27,6 +27,7 @@ impl Runner {
     ///
     /// # Errors
     ///
+    /// Fail if the runner does not provide a compose command, see [`RunnerError::ComposeUnavailable`]
     /// Fail if the compose containers cannot be started
     pub async fn compose_start_with_options<I>(
         &self,
@@ -36,12 +37,19 @@ impl Runner {
     where
         I: ToRunnableComposeContainers,
     {
+        // Fail fast without a compose command
+        if !self.supports(Feature::Compose) {
+            return Err(RunnerError::ComposeUnavailable {
+                runner: self.clone(),
+            });
+        }
+
         let mut containers = images.to_runnable(RunnableComposeContainers::builder());
         let file = containers.compose_path.as_ref();
         let wait = &containers.wait_strategies;
         let mappings = &mut containers.port_mappings;
 
-        let name = match self {
+        let (project, reused) = match self {
             Runner::Docker(runner) => runner.start_compose(file, wait, mappings, options).await,
             Runner::Podman(runner) => runner.start_compose(file, wait, mappings, options).await,
             Runner::Nerdctl(runner) => runner.start_compose(file, wait, mappings, options).await,
@@ -54,23 +62,95 @@ impl Runner {
 
         Ok(ComposeContainers {
             runner: self.clone(),
-            name,
+            project,
             images,
-            file: containers.compose_path,
-            detached: false,
+            _file: containers.compose_path,
+            port_mappings: containers.port_mappings,
+            // Reused containers are not stopped
+            detached: reused,
+            stopped: false,
         })
     }
 
-    pub(crate) fn compose_stop(&self, name: &str, file: &Path) -> Result<(), ComposeError> {
-        if !file.exists() {
-            return Err(ComposeError::ComposeFileMissing(file.to_path_buf()));
+    /// Validate the compose file, and render the effective configuration with the default options
+    ///
+    /// See [`Runner::compose_config_with_options`]
+    ///
+    /// # Errors
+    ///
+    /// Fail if the compose file is invalid
+    pub async fn compose_config(&self, path: impl AsRef<Path>) -> Result<String, ComposeError> {
+        let options = ComposeRunOption::default();
+        self.compose_config_with_options(path, &options).await
+    }
+
+    /// Validate the compose file, and render the effective configuration
+    ///
+    /// It runs the `compose config` command, it merges the compose files,
+    /// and interpolates the environment variables.
+    /// The path could be the directory containing the compose file, or directly the compose file.
+    ///
+    /// # Errors
+    ///
+    /// Fail if the compose file is invalid
+    pub async fn compose_config_with_options(
+        &self,
+        path: impl AsRef<Path>,
+        options: &ComposeRunOption,
+    ) -> Result<String, ComposeError> {
+        let project = ResolvedComposeProject::new(path.as_ref(), options)?;
+        match self {
+            Runner::Docker(runner) => runner.compose_config(&project, options).await,
+            Runner::Podman(runner) => runner.compose_config(&project, options).await,
+            Runner::Nerdctl(runner) => runner.compose_config(&project, options).await,
+        }
+    }
+
+    pub(crate) async fn compose_service_states(
+        &self,
+        project: &ResolvedComposeProject,
+    ) -> Result<Vec<ServiceStatus>, RunnerError> {
+        match self {
+            Runner::Docker(runner) => runner.compose_ps(project).await,
+            Runner::Podman(runner) => runner.compose_ps(project).await,
+            Runner::Nerdctl(runner) => runner.compose_ps(project).await,
         }
+        .map_err(|source| RunnerError::ComposeError {
+            runner: self.clone(),
+            path: project.dir.clone(),
+            source: Box::new(source),
+        })
+    }
+
+    pub(crate) async fn compose_down(
+        &self,
+        project: &ResolvedComposeProject,
+    ) -> Result<(), RunnerError> {
+        match self {
+            Runner::Docker(runner) => runner.compose_down(project).await,
+            Runner::Podman(runner) => runner.compose_down(project).await,
+            Runner::Nerdctl(runner) => runner.compose_down(project).await,
+        }
+        .map_err(|source| RunnerError::ComposeError {
+            runner: self.clone(),
+            path: project.dir.clone(),
+            source: Box::new(source),
+        })?;
+        info!(name = %project.name, "🛑 Compose containers stopped");
+        Ok(())
+    }
+
+    pub(crate) fn compose_stop(
+        &self,
+        project: &ResolvedComposeProject,
+    ) -> Result<(), ComposeError> {
+        project.check_exists()?;
+        let name = &project.name;
         let mut cmd = match self {
-            Runner::Docker(runner) => runner.compose_command()?,
-            Runner::Podman(runner) => runner.compose_command()?,
-            Runner::Nerdctl(runner) => runner.compose_command()?,
+            Runner::Docker(runner) => runner.project_compose_command(project)?,
+            Runner::Podman(runner) => runner.project_compose_command(project)?,
+            Runner::Nerdctl(runner) => runner.project_compose_command(project)?,
         };
-        cmd.with_dir(file);
         cmd.push_args(["down"]);
         let status = cmd.status_blocking()?;
         if status.success() {
@@ -83,18 +163,17 @@ impl Runner {
 }
 
 mod docker {
-    use std::path::Path;
 
     use async_trait::async_trait;
 
     use crate::cmd::Cmd;
-    use crate::compose::{ComposeError, ComposeServiceState, InnerComposeRunner, Services};
+    use crate::compose::{
+        ComposeError, ComposeServiceState, InnerComposeRunner, ResolvedComposeProject,
+        ServiceStatus,
+    };
     use crate::runner::{Docker, InnerRunner};
     use crate::version::Version;
 
-    // https://docs.docker.com/compose/release-notes/#2210
-    const PS_JSON_LINES_MINIMAL_VERSION: Version = Version::new(2, 21);
-
     // See <https://docs.docker.com/compose/release-notes/#2230>
     const NO_TRUNC_MINIMAL_VERSION: Version = Version::new(2, 23);
 
@@ -103,35 +182,35 @@ mod docker {
         fn compose_command(&self) -> Result<Cmd<'static>, ComposeError> {
             if self.compose_version.is_none() {
                 return Err(ComposeError::UnsupportedComposeCommand(self.to_string()));
-            };
+            }
             let mut cmd = self.command();
-            cmd.push_arg("compose");
+            if let Some(binary) = &self.config.compose_binary {
+                cmd.set_program(binary.clone());
+            } else {
+                cmd.push_arg("compose");
+            }
             cmd.ignore_stderr();
             Ok(cmd)
         }
 
-        async fn compose_look_up_services(
+        async fn compose_ps(
             &self,
-            _name: &str,
-            path: &Path,
-        ) -> Result<Services, ComposeError> {
-            let mut cmd = self.compose_command()?;
-            cmd.with_dir(path);
+            project: &ResolvedComposeProject,
+        ) -> Result<Vec<ServiceStatus>, ComposeError> {
+            let mut cmd = self.project_compose_command(project)?;
             let compose_version = self
                 .compose_version
                 .ok_or(ComposeError::MissingComposeVersion)?;
 
-            let services = if compose_version >= NO_TRUNC_MINIMAL_VERSION {
-                cmd.push_args(["ps", "--all", "--no-trunc", "--format", "json"]);
-                cmd.json_stream::<ComposeServiceState>().await?
-            } else if compose_version >= PS_JSON_LINES_MINIMAL_VERSION {
-                cmd.push_args(["ps", "--all", "--format", "json"]);
-                cmd.json_stream::<ComposeServiceState>().await?
-            } else {
-                cmd.push_args(["ps", "--all", "--format", "json"]);
-                cmd.json::<Vec<ComposeServiceState>>().await?
-            };
-            let result = Services::from(services);
+            cmd.push_args(["ps", "--all"]);
+            if compose_version >= NO_TRUNC_MINIMAL_VERSION {
+                cmd.push_arg("--no-trunc");
+            }
+            cmd.push_args(["--format", "json"]);
+            // Before v2.21, the output is a JSON array, then a JSON object per line
+            // See <https://docs.docker.com/compose/release-notes/#2210>
+            let services = cmd.json_list::<ComposeServiceState>().await?;
+            let result = services.into_iter().map(ServiceStatus::from).collect();
 
             Ok(result)
         }
@@ -156,13 +235,12 @@ mod nerdctl {
 }
 
 mod podman {
-    use std::path::Path;
 
     use async_trait::async_trait;
     use serde::{Deserialize, Serialize};
 
     use crate::cmd::Cmd;
-    use crate::compose::{ComposeError, ComposeService, InnerComposeRunner, Services};
+    use crate::compose::{ComposeError, InnerComposeRunner, ResolvedComposeProject, ServiceStatus};
     use crate::runner::{InnerRunner, Podman};
     use crate::{ContainerHealth, ContainerId, ContainerStatus};
 
@@ -171,30 +249,37 @@ mod podman {
         fn compose_command(&self) -> Result<Cmd<'static>, ComposeError> {
             if self.compose_version.is_none() {
                 return Err(ComposeError::UnsupportedComposeCommand(self.to_string()));
+            }
+            let mut cmd = if let Some(binary) = &self.config.compose_binary {
+                Cmd::new(binary.clone())
+            } else {
+                self.compose_command.command()
             };
-            let mut cmd = Cmd::new("podman-compose");
+            self.push_auth_env(&mut cmd);
             cmd.ignore_stderr();
             Ok(cmd)
         }
 
-        async fn compose_look_up_services(
+        fn project_name_flag(&self) -> &'static str {
+            // The provider could be podman-compose, or docker-compose (v1 or v2),
+            // the short form is supported by all of them
+            "-p"
+        }
+
+        async fn compose_ps(
             &self,
-            name: &str,
-            _path: &Path,
-        ) -> Result<Services, ComposeError> {
+            project: &ResolvedComposeProject,
+        ) -> Result<Vec<ServiceStatus>, ComposeError> {
             // To use the JSON output, we need to use the standard ps command of podman
             let mut cmd = self.command();
             let label = format!(
                 "label=io.podman.compose.project={}",
-                name.to_ascii_lowercase()
+                project.name.to_ascii_lowercase()
             );
             cmd.push_args(["ps", "--all", "--filter", &label, "--format", "json"]);
             let containers = cmd.json::<Vec<PodmanComposeServiceState>>().await?;
-            let result = containers
-                .into_iter()
-                .map(|it| (ComposeService::from(it.labels.service), it.id))
-                .collect();
-            Ok(Services(result))
+            let result = containers.into_iter().map(ServiceStatus::from).collect();
+            Ok(result)
         }
     }
 
@@ -209,6 +294,27 @@ mod podman {
         exit_code: Option<i32>,
     }
 
+    impl From<PodmanComposeServiceState> for ServiceStatus {
+        fn from(value: PodmanComposeServiceState) -> Self {
+            let PodmanComposeServiceState {
+                id,
+                names,
+                labels,
+                state,
+                health,
+                exit_code,
+            } = value;
+            Self {
+                service: labels.service,
+                name: names.into_iter().next().unwrap_or_default(),
+                id,
+                state,
+                health: health.unwrap_or_default(),
+                exit_code,
+            }
+        }
+    }
+
     #[derive(Debug, Clone, Serialize, Deserialize)]
     pub struct PodmanComposeLabels {
         #[serde(rename = "com.docker.compose.container-number")]
@@ -225,6 +331,8 @@ mod podman {
 
     #[cfg(test)]
     mod tests {
+        use assert2::{check, let_assert};
+
         use super::*;
 
         #[test]
@@ -234,5 +342,125 @@ mod podman {
                 serde_json::from_str::<Vec<PodmanComposeServiceState>>(json).expect("json");
             insta::assert_json_snapshot!(services);
         }
+
+        #[test]
+        fn should_convert_podman_service_status() {
+            let json = include_str!("../../tests/assets/podman_lookup.json");
+            let services =
+                serde_json::from_str::<Vec<PodmanComposeServiceState>>(json).expect("json");
+            let statuses = services
+                .into_iter()
+                .map(ServiceStatus::from)
+                .collect::<Vec<_>>();
+            let_assert!(Some(kafka) = statuses.iter().find(|it| it.service == "kafka"));
+            check!(kafka.name == "tc-kafka");
+            check!(kafka.state == ContainerStatus::Running);
+            check!(kafka.health == ContainerHealth::Unknown);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+    use std::sync::Arc;
+
+    use assert2::{check, let_assert};
+    use rstest::rstest;
+
+    use super::*;
+    use crate::compose::{
+        ComposeRunOption, ResolvedComposeProject, RunnableComposeContainersBuilder,
+    };
+    use crate::runner::{Docker, Nerdctl, Podman, PodmanComposeCommand, RunnerConfig};
+    use crate::version::Version;
+
+    #[derive(Debug)]
+    struct Unreachable;
+
+    impl ToRunnableComposeContainers for Unreachable {
+        type AsPath = PathBuf;
+
+        fn to_runnable(
+            &self,
+            _builder: RunnableComposeContainersBuilder<Self::AsPath>,
+        ) -> RunnableComposeContainers<Self::AsPath> {
+            unreachable!("compose should be checked before")
+        }
+    }
+
+    #[tokio::test]
+    async fn should_fail_fast_without_compose() {
+        let runner = Runner::Docker(Docker {
+            version: Version::new(1, 43),
+            compose_version: None,
+            rootless: false,
+            config: Arc::default(),
+        });
+        let result = runner.compose_start(Unreachable).await;
+        let_assert!(Err(RunnerError::ComposeUnavailable { .. }) = result);
+    }
+
+    #[test]
+    fn should_use_compose_binary() {
+        let docker = Docker {
+            version: Version::new(1, 43),
+            compose_version: Some(Version::new(2, 24)),
+            rootless: false,
+            config: Arc::new(RunnerConfig {
+                compose_binary: Some(String::from("docker-compose")),
+                ..RunnerConfig::default()
+            }),
+        };
+        let_assert!(Ok(cmd) = docker.compose_command());
+        check!(cmd.to_string() == "docker-compose");
+        check!(docker.to_string() == "Docker 1.43 - docker-compose 2.24");
+    }
+
+    fn docker() -> Runner {
+        Runner::Docker(Docker {
+            version: Version::new(1, 43),
+            compose_version: Some(Version::new(2, 24)),
+            rootless: false,
+            config: Arc::default(),
+        })
+    }
+
+    fn podman(compose_command: PodmanComposeCommand) -> Runner {
+        Runner::Podman(Podman {
+            version: Version::new(4, 9),
+            compose_command,
+            compose_version: Some(Version::new(1, 0)),
+            config: Arc::default(),
+        })
+    }
+
+    fn nerdctl() -> Runner {
+        Runner::Nerdctl(Nerdctl {
+            version: Version::new(1, 7),
+            config: Arc::default(),
+        })
+    }
+
+    #[rstest]
+    #[case::docker(docker(), "docker compose --project-name plop")]
+    #[case::podman_native(podman(PodmanComposeCommand::Native), "podman compose -p plop")]
+    #[case::podman_compose(podman(PodmanComposeCommand::PodmanCompose), "podman-compose -p plop")]
+    #[case::nerdctl(nerdctl(), "nerdctl compose --project-name plop")]
+    fn should_use_project_name_flag(#[case] runner: Runner, #[case] expected: &str) {
+        let options = ComposeRunOption::builder()
+            .with_project_name("plop")
+            .build();
+        let_assert!(
+            Ok(project) = ResolvedComposeProject::new(Path::new("/tmp/my-project"), &options)
+        );
+
+        let result = match &runner {
+            Runner::Docker(runner) => runner.project_compose_command(&project),
+            Runner::Podman(runner) => runner.project_compose_command(&project),
+            Runner::Nerdctl(runner) => runner.project_compose_command(&project),
+        };
+        let_assert!(Ok(cmd) = result);
+        check!(cmd.to_string() == expected);
     }
 }
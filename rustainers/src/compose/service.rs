@@ -9,7 +9,7 @@ use tracing::debug;
 
 use crate::ContainerId;
 
-use super::ComposeServiceState;
+use super::ServiceStatus;
 
 /// A Compose containers service
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -83,8 +83,8 @@ impl Index<&ComposeService> for Services {
     }
 }
 
-impl From<Vec<ComposeServiceState>> for Services {
-    fn from(value: Vec<ComposeServiceState>) -> Self {
+impl From<Vec<ServiceStatus>> for Services {
+    fn from(value: Vec<ServiceStatus>) -> Self {
         let map = value
             .into_iter()
             .map(|state| (ComposeService::from(state.service), state.id))
@@ -14,3 +14,69 @@ pub struct ComposeServiceState {
     health: ContainerHealth,
     exit_code: Option<i32>,
 }
+
+/// The status of a compose service container
+///
+/// See [`ComposeContainers::service_states`](super::ComposeContainers::service_states)
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ServiceStatus {
+    /// The service name
+    pub service: String,
+    /// The container name
+    pub name: String,
+    /// The container id
+    pub id: ContainerId,
+    /// The container state
+    pub state: ContainerStatus,
+    /// The container health, [`ContainerHealth::Unknown`] without health check
+    pub health: ContainerHealth,
+    /// The container exit code
+    pub exit_code: Option<i32>,
+}
+
+impl From<ComposeServiceState> for ServiceStatus {
+    fn from(value: ComposeServiceState) -> Self {
+        let ComposeServiceState {
+            id,
+            name,
+            service,
+            state,
+            health,
+            exit_code,
+        } = value;
+        Self {
+            service,
+            name,
+            id,
+            state,
+            health,
+            exit_code,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+
+    use super::*;
+
+    #[test]
+    fn should_serde_docker_compose_service() {
+        let json_stream = include_str!("../../tests/assets/docker-compose_ps.jsonl");
+        let stream =
+            serde_json::Deserializer::from_str(json_stream).into_iter::<ComposeServiceState>();
+        let result = stream.collect::<Result<Vec<_>, _>>();
+        let_assert!(Ok(states) = result);
+        let statuses = states
+            .into_iter()
+            .map(ServiceStatus::from)
+            .collect::<Vec<_>>();
+        let_assert!(Some(kafdrop) = statuses.iter().find(|it| it.service == "my-kafdrop"));
+        check!(kafdrop.name == "kafka-kraft-my-kafdrop-1");
+        check!(kafdrop.state == ContainerStatus::Running);
+        check!(kafdrop.health == ContainerHealth::Unknown);
+        check!(kafdrop.exit_code == Some(0));
+    }
+}
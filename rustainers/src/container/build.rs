@@ -0,0 +1,180 @@
+use std::path::PathBuf;
+
+use indexmap::IndexMap;
+use typed_builder::TypedBuilder;
+use ulid::Ulid;
+
+use crate::{
+    ExposedPort, ImageName, ImageReference, RunnableContainer, RunnableContainerBuilder,
+    ToRunnableContainer, WaitStrategy,
+};
+
+const BUILD_REPOSITORY: &str = "localhost/rustainers-build";
+
+/// A build context, used to build the image before starting the container
+///
+/// The image is built with `{runner} build --tag {image} [--file {dockerfile}] {context}`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuildContext {
+    pub(crate) context: PathBuf,
+    pub(crate) dockerfile: Option<PathBuf>,
+}
+
+impl BuildContext {
+    /// Create a build context from a directory
+    #[must_use]
+    pub fn new(context: impl Into<PathBuf>) -> Self {
+        Self {
+            context: context.into(),
+            dockerfile: None,
+        }
+    }
+
+    /// Set the `Dockerfile` path
+    ///
+    /// By default, the runner uses the `Dockerfile` of the context directory.
+    #[must_use]
+    pub fn with_dockerfile(self, dockerfile: impl Into<PathBuf>) -> Self {
+        let dockerfile = Some(dockerfile.into());
+        Self { dockerfile, ..self }
+    }
+
+    pub(crate) fn to_args(&self, image: &ImageReference) -> Vec<String> {
+        let mut args = vec![String::from("--tag"), image.to_string()];
+        if let Some(dockerfile) = &self.dockerfile {
+            args.push(String::from("--file"));
+            args.push(dockerfile.display().to_string());
+        }
+        args.push(self.context.display().to_string());
+        args
+    }
+}
+
+/// An image built from a local `Dockerfile`
+///
+/// The image is built before starting the container,
+/// by default it's tagged with a unique generated tag, like `localhost/rustainers-build:{ulid}`.
+/// The layer caching is left to the runner build.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// # async fn run() -> anyhow::Result<()> {
+/// use rustainers::{BuildImage, ExposedPort};
+///
+/// let image = BuildImage::builder()
+///     .with_context("./my-app")
+///     .with_dockerfile("./my-app/Dockerfile.test")
+///     .with_port_mappings([ExposedPort::new(8080)])
+///     .build();
+///
+/// # let runner = rustainers::runner::Runner::auto()?;
+/// let container = runner.start(image).await?;
+/// // ...
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, TypedBuilder)]
+#[builder(field_defaults(setter(prefix = "with_")))]
+pub struct BuildImage {
+    /// The build context directory
+    #[builder(setter(into))]
+    context: PathBuf,
+
+    /// The `Dockerfile` path
+    #[builder(default, setter(strip_option, into))]
+    dockerfile: Option<PathBuf>,
+
+    /// The built image name
+    #[builder(default = generated_image_name())]
+    image: ImageName,
+
+    /// The command
+    #[builder(default, setter(transform = |args: impl IntoIterator<Item = impl Into<String>>| args.into_iter().map(Into::into).collect()))]
+    command: Vec<String>,
+
+    /// The environment variables
+    #[builder(default, setter(transform = |args: impl IntoIterator<Item = (impl Into<String>, impl Into<String>)>| args.into_iter().map(|(key, value)| (key.into(), value.into())).collect()))]
+    env: IndexMap<String, String>,
+
+    /// The wait strategy
+    #[builder(default, setter(into))]
+    wait_strategy: WaitStrategy,
+
+    /// The ports mapping
+    #[builder(default, setter(transform = |args: impl IntoIterator<Item = ExposedPort>| args.into_iter().collect()))]
+    port_mappings: Vec<ExposedPort>,
+}
+
+impl BuildImage {
+    /// The built image name
+    #[must_use]
+    pub fn image(&self) -> &ImageName {
+        &self.image
+    }
+
+    pub(crate) fn build_context(&self) -> BuildContext {
+        BuildContext {
+            context: self.context.clone(),
+            dockerfile: self.dockerfile.clone(),
+        }
+    }
+}
+
+/// Generate a unique image name
+fn generated_image_name() -> ImageName {
+    let tag = Ulid::new().to_string().to_lowercase();
+    let mut image = ImageName::new(BUILD_REPOSITORY);
+    image.set_tag(tag);
+    image
+}
+
+impl ToRunnableContainer for BuildImage {
+    fn to_runnable(&self, builder: RunnableContainerBuilder) -> RunnableContainer {
+        builder
+            .with_image(self.image.clone())
+            .with_build(self.build_context())
+            .with_command(self.command.clone())
+            .with_env(self.env.clone())
+            .with_wait_strategy(self.wait_strategy.clone())
+            .with_port_mappings(self.port_mappings.clone())
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::check;
+
+    use super::*;
+
+    #[test]
+    fn should_build_args() {
+        let image = ImageName::new_with_tag("localhost/my-app", "test").into();
+        let build = BuildContext::new("./app").with_dockerfile("./app/Dockerfile.test");
+        let args = build.to_args(&image);
+        check!(
+            args == [
+                "--tag",
+                "localhost/my-app:test",
+                "--file",
+                "./app/Dockerfile.test",
+                "./app"
+            ]
+        );
+    }
+
+    #[test]
+    fn should_generate_image_name() {
+        let image = BuildImage::builder()
+            .with_context("./tests/assets/build")
+            .build();
+        let other = BuildImage::builder()
+            .with_context("./tests/assets/build")
+            .build();
+        let runnable = image.to_runnable(RunnableContainer::builder());
+        check!(image.image().to_string().starts_with(BUILD_REPOSITORY));
+        check!(image.image() != other.image());
+        check!(runnable.build.is_some());
+    }
+}
@@ -0,0 +1,151 @@
+use std::collections::BTreeMap;
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Deserializer};
+
+use crate::{ExposedPort, PortProtocol};
+
+/// The container configuration, i.e. the `.Config` of the `inspect` command
+///
+/// See [`Container::container_config`](crate::Container::container_config)
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ContainerConfig {
+    #[serde(default)]
+    exposed_ports: Option<BTreeMap<String, serde_json::Value>>,
+    #[serde(default)]
+    env: Option<Vec<String>>,
+    #[serde(default, deserialize_with = "deserialize_command")]
+    entrypoint: Option<Vec<String>>,
+    #[serde(default, deserialize_with = "deserialize_command")]
+    cmd: Option<Vec<String>>,
+    #[serde(default)]
+    labels: Option<BTreeMap<String, String>>,
+    #[serde(default)]
+    working_dir: Option<String>,
+}
+
+impl ContainerConfig {
+    /// The exposed ports
+    ///
+    /// The ports are sorted by container port,
+    /// they could be used directly as port mappings.
+    #[must_use]
+    pub fn exposed_ports(&self) -> Vec<ExposedPort> {
+        let mut result = self
+            .exposed_ports
+            .iter()
+            .flatten()
+            .filter_map(|(port, _)| parse_exposed_port(port))
+            .collect::<Vec<_>>();
+        result.sort_by_key(|port| port.container_port);
+        result
+    }
+
+    /// The environment variables
+    #[must_use]
+    pub fn env(&self) -> IndexMap<String, String> {
+        self.env
+            .iter()
+            .flatten()
+            .map(|var| match var.split_once('=') {
+                Some((key, value)) => (key.to_string(), value.to_string()),
+                None => (var.clone(), String::new()),
+            })
+            .collect()
+    }
+
+    /// The entrypoint
+    #[must_use]
+    pub fn entrypoint(&self) -> &[String] {
+        self.entrypoint.as_deref().unwrap_or_default()
+    }
+
+    /// The command
+    #[must_use]
+    pub fn cmd(&self) -> &[String] {
+        self.cmd.as_deref().unwrap_or_default()
+    }
+
+    /// The labels
+    #[must_use]
+    pub fn labels(&self) -> BTreeMap<&str, &str> {
+        self.labels
+            .iter()
+            .flatten()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+            .collect()
+    }
+
+    /// The working directory, if any
+    #[must_use]
+    pub fn working_dir(&self) -> Option<&str> {
+        self.working_dir.as_deref().filter(|dir| !dir.is_empty())
+    }
+}
+
+/// The exposed port looks like `80/tcp`, or `53/udp`
+fn parse_exposed_port(str: &str) -> Option<ExposedPort> {
+    let (port, protocol) = str.split_once('/').unwrap_or((str, "tcp"));
+    let protocol = match protocol {
+        "tcp" => PortProtocol::Tcp,
+        "udp" => PortProtocol::Udp,
+        _ => return None,
+    };
+    let port = port.parse::<u16>().ok()?;
+    Some(ExposedPort::new(port).with_protocol(protocol))
+}
+
+/// The command is a list of strings, but podman (before v5) provides the entrypoint as a string
+fn deserialize_command<'de, D>(deserializer: D) -> Result<Option<Vec<String>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Command {
+        List(Vec<String>),
+        Str(String),
+    }
+
+    let result = Option::<Command>::deserialize(deserializer)?.map(|cmd| match cmd {
+        Command::List(args) => args,
+        Command::Str(str) => str.split_whitespace().map(String::from).collect(),
+    });
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+
+    use super::*;
+
+    #[test]
+    fn should_serde_docker_config() {
+        let json = include_str!("../../tests/assets/docker-inspect-config.json");
+        let result = serde_json::from_str::<ContainerConfig>(json);
+        let_assert!(Ok(config) = result);
+
+        check!(config.exposed_ports() == [ExposedPort::new(6379)]);
+        check!(config.env().get("REDIS_VERSION").map(String::as_str) == Some("7.2.4"));
+        check!(config.entrypoint() == ["docker-entrypoint.sh"]);
+        check!(config.cmd() == ["redis-server"]);
+        check!(config.labels().get("com.example.team") == Some(&"platform"));
+        check!(config.working_dir() == Some("/data"));
+    }
+
+    #[test]
+    fn should_serde_podman_config() {
+        let json = include_str!("../../tests/assets/podman-inspect-config.json");
+        let result = serde_json::from_str::<ContainerConfig>(json);
+        let_assert!(Ok(config) = result);
+
+        check!(config.exposed_ports().is_empty());
+        check!(config.env().get("REDIS_VERSION").map(String::as_str) == Some("7.2.4"));
+        check!(config.entrypoint() == ["docker-entrypoint.sh"]);
+        check!(config.cmd() == ["redis-server"]);
+        check!(config.labels().is_empty());
+        check!(config.working_dir() == Some("/data"));
+    }
+}
@@ -0,0 +1,21 @@
+/// The output of a command executed into a container
+///
+/// See [`Runner::exec_output`](crate::runner::Runner::exec_output)
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ExecOutput {
+    /// The exit code
+    pub exit_code: i32,
+    /// The standard output
+    pub stdout: String,
+    /// The standard error
+    pub stderr: String,
+}
+
+impl ExecOutput {
+    /// If the command succeed, i.e. the exit code is `0`
+    #[must_use]
+    pub fn is_success(&self) -> bool {
+        self.exit_code == 0
+    }
+}
@@ -0,0 +1,105 @@
+use std::fmt::{self, Display};
+use std::ops::Deref;
+use std::sync::atomic::Ordering;
+
+use tracing::warn;
+
+use crate::runner::RunnerError;
+use crate::{Container, ToRunnableContainer};
+
+/// A scoped container, with an explicit async cleanup
+///
+/// Unlike the [`Container`] drop, that logs the stop error,
+/// the [`ContainerGuard::cleanup`] returns the error.
+///
+/// If the guard is dropped without a cleanup, the container is stopped during the drop,
+/// like a [`Container`].
+///
+/// It implements [`std::ops::Deref`] for the container.
+///
+/// ```rust, no_run
+/// # async fn run() -> anyhow::Result<()> {
+/// use rustainers::images::Redis;
+/// use rustainers::runner::Runner;
+///
+/// let runner = Runner::auto()?;
+/// let container = runner.start_scoped(Redis::default()).await?;
+/// let endpoint = container.endpoint().await?;
+/// // ...
+/// container.cleanup().await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ContainerGuard<I>
+where
+    I: ToRunnableContainer,
+{
+    container: Container<I>,
+    cleaned: bool,
+}
+
+impl<I> ContainerGuard<I>
+where
+    I: ToRunnableContainer,
+{
+    /// Stop and remove the container
+    ///
+    /// The container is stopped even if it's detached.
+    ///
+    /// # Errors
+    ///
+    /// Fail if the container cannot be stopped, or removed
+    pub async fn cleanup(mut self) -> Result<(), RunnerError> {
+        self.container
+            .runner
+            .stop_and_remove(&self.container)
+            .await?;
+        self.container.stopped.store(true, Ordering::Release);
+        self.cleaned = true;
+        Ok(())
+    }
+}
+
+impl<I> From<Container<I>> for ContainerGuard<I>
+where
+    I: ToRunnableContainer,
+{
+    fn from(container: Container<I>) -> Self {
+        Self {
+            container,
+            cleaned: false,
+        }
+    }
+}
+
+impl<I> Deref for ContainerGuard<I>
+where
+    I: ToRunnableContainer,
+{
+    type Target = Container<I>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.container
+    }
+}
+
+impl<I> Drop for ContainerGuard<I>
+where
+    I: ToRunnableContainer,
+{
+    fn drop(&mut self) {
+        if !self.cleaned {
+            warn!("Container {} is dropped without a cleanup", self.container);
+        }
+    }
+}
+
+impl<I> Display for ContainerGuard<I>
+where
+    I: ToRunnableContainer,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.container)
+    }
+}
@@ -4,7 +4,7 @@ use tracing::warn;
 /// The container health
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, strum_macros::Display)]
 #[serde(rename_all = "lowercase")]
-pub(crate) enum ContainerHealth {
+pub enum ContainerHealth {
     /// Unknown
     #[default]
     Unknown,
@@ -29,7 +29,8 @@ impl<'de> Deserialize<'de> for ContainerHealth {
             "starting" => Self::Starting,
             "healthy" => Self::Healthy,
             "unhealthy" => Self::Unhealthy,
-            "unknown" => Self::Unknown,
+            // Docker compose provides an empty health without health check
+            "unknown" | "" => Self::Unknown,
             _ => {
                 warn!(?state, "Oops, found an unknown container health");
                 Self::Unknown
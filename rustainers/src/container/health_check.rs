@@ -16,14 +16,25 @@ use typed_builder::TypedBuilder;
 ///     .build();
 /// ```
 ///
+/// Use [`HealthCheckBuilder::with_command_exec`] to avoid the shell parsing of the command:
+///
+/// ```rust
+/// # use rustainers::HealthCheck;
+/// let hc = HealthCheck::builder()
+///     .with_command_exec(["pg_isready", "--dbname=my db"])
+///     .build();
+/// ```
+///
 /// Note that the command is executed inside the container
 // TODO maybe a macro rules can help to create the Heathcheck?
 #[derive(Debug, Clone, PartialEq, Eq, TypedBuilder)]
 #[builder(field_defaults(setter(prefix = "with_")))]
 pub struct HealthCheck {
     /// Command to run to check health
+    ///
+    /// A string command uses the shell form
     #[builder(setter(into))]
-    command: String,
+    command: HealthCheckCommand,
 
     /// Time between running the check
     #[builder(default = Duration::from_secs(1))]
@@ -42,10 +53,29 @@ pub struct HealthCheck {
     timeout: Duration,
 }
 
+impl<I, R, S, T> HealthCheckBuilder<((), I, R, S, T)> {
+    /// Set the command with the shell form (`CMD-SHELL`)
+    pub fn with_command_shell(
+        self,
+        command: impl Into<String>,
+    ) -> HealthCheckBuilder<((HealthCheckCommand,), I, R, S, T)> {
+        self.with_command(HealthCheckCommand::Shell(command.into()))
+    }
+
+    /// Set the command with the exec form (`CMD`)
+    pub fn with_command_exec(
+        self,
+        command: impl IntoIterator<Item = impl Into<String>>,
+    ) -> HealthCheckBuilder<((HealthCheckCommand,), I, R, S, T)> {
+        let args = command.into_iter().map(Into::into).collect();
+        self.with_command(HealthCheckCommand::Exec(args))
+    }
+}
+
 impl HealthCheck {
     pub(crate) fn to_vec(&self) -> Vec<String> {
         vec![
-            format!("--health-cmd={}", self.command),
+            format!("--health-cmd={}", self.command.to_shell()),
             format!("--health-interval={}ms", self.interval.as_millis()),
             format!("--health-retries={}", self.retries),
             format!("--health-start-period={}ms", self.start_period.as_millis()),
@@ -53,3 +83,71 @@ impl HealthCheck {
         ]
     }
 }
+
+/// A health check command
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HealthCheckCommand {
+    /// The shell form (`CMD-SHELL`), the command is run by the container shell
+    Shell(String),
+
+    /// The exec form (`CMD`), arguments are not interpreted by the shell
+    Exec(Vec<String>),
+}
+
+impl HealthCheckCommand {
+    fn to_shell(&self) -> String {
+        match self {
+            Self::Shell(command) => command.clone(),
+            // The CLI always use the shell form, so we quote each argument
+            Self::Exec(args) => args
+                .iter()
+                .map(|arg| format!("'{}'", arg.replace('\'', r"'\''")))
+                .collect::<Vec<_>>()
+                .join(" "),
+        }
+    }
+}
+
+impl From<String> for HealthCheckCommand {
+    fn from(value: String) -> Self {
+        Self::Shell(value)
+    }
+}
+
+impl From<&str> for HealthCheckCommand {
+    fn from(value: &str) -> Self {
+        Self::Shell(String::from(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::shell(
+        HealthCheckCommand::from("pg_isready && echo ok"),
+        "pg_isready && echo ok"
+    )]
+    #[case::exec(
+        HealthCheckCommand::Exec(vec!["echo".into(), "a && b".into()]),
+        "'echo' 'a && b'"
+    )]
+    #[case::exec_quote(HealthCheckCommand::Exec(vec!["it's".into()]), r"'it'\''s'")]
+    fn should_render_shell_command(#[case] command: HealthCheckCommand, #[case] expected: &str) {
+        check!(command.to_shell() == expected);
+    }
+
+    #[test]
+    fn should_provide_health_cmd_arg() {
+        let hc = HealthCheck::builder()
+            .with_command_exec(["redis-cli", "ping"])
+            .build();
+        let args = hc.to_vec();
+        let_assert!(Some(arg) = args.first());
+        check!(arg == "--health-cmd='redis-cli' 'ping'");
+    }
+}
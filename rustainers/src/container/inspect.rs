@@ -0,0 +1,76 @@
+use serde::Deserialize;
+
+use crate::{ContainerId, ContainerState, IdError, ImageId, ImageName, ImageReference};
+
+/// The container `inspect` output, used to attach an existing container
+///
+/// See [`Runner::attach_existing`](crate::runner::Runner::attach_existing)
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct ContainerInspect {
+    pub(crate) id: ContainerId,
+    /// The image id
+    #[serde(default)]
+    pub(crate) image: String,
+    #[serde(default)]
+    pub(crate) config: ContainerInspectConfig,
+    #[serde(default)]
+    pub(crate) state: ContainerState,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct ContainerInspectConfig {
+    /// The image name used to create the container
+    #[serde(default)]
+    pub(crate) image: String,
+}
+
+impl ContainerInspect {
+    /// The image reference, the image name if available, or the image id
+    pub(crate) fn image_ref(&self) -> Result<ImageReference, IdError> {
+        if let Ok(name) = self.config.image.parse::<ImageName>() {
+            return Ok(ImageReference::Name(name));
+        }
+        let id = self
+            .image
+            .trim_start_matches("sha256:")
+            .parse::<ImageId>()?;
+        Ok(ImageReference::Id(id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+
+    use super::*;
+    use crate::ContainerStatus;
+
+    #[test]
+    fn should_serde_container_inspect() {
+        let json = include_str!("../../tests/assets/docker-container-inspect.json");
+        let_assert!(Ok(result) = serde_json::from_str::<Vec<ContainerInspect>>(json));
+        let_assert!([inspect] = result.as_slice());
+
+        check!(
+            String::from(inspect.id)
+                == "8b9c0f3a4e1d2c5b6a7f8e9d0c1b2a3f4e5d6c7b8a9f0e1d2c3b4a5f6e7d8c9b"
+        );
+        check!(inspect.state.status == ContainerStatus::Running);
+        let_assert!(Ok(ImageReference::Name(name)) = inspect.image_ref());
+        check!(name.to_string() == "docker.io/nginx:1.25");
+    }
+
+    #[test]
+    fn should_fallback_to_image_id() {
+        let json = r#"{
+            "Id": "8b9c0f3a4e1d2c5b6a7f8e9d0c1b2a3f4e5d6c7b8a9f0e1d2c3b4a5f6e7d8c9b",
+            "Image": "sha256:a8758716bb6aa4d90071160d27028fe4eaee7ce8166221a97d30440c8eac2be6",
+            "Config": { "Image": "" },
+            "State": { "Status": "running" }
+        }"#;
+        let_assert!(Ok(inspect) = serde_json::from_str::<ContainerInspect>(json));
+        let_assert!(Ok(ImageReference::Id(_)) = inspect.image_ref());
+    }
+}
@@ -0,0 +1,56 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// The start of the retrieved logs, see [`crate::Container::logs_since`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogsSince {
+    /// The logs produced after this time
+    Time(SystemTime),
+
+    /// The logs produced during this last duration
+    Relative(Duration),
+}
+
+impl LogsSince {
+    /// The `--since` value
+    ///
+    /// A time is rendered as an Unix timestamp, a duration as a number of milliseconds, e.g. `1500ms`
+    pub(crate) fn to_arg(self) -> String {
+        match self {
+            Self::Time(time) => {
+                let elapsed = time.duration_since(UNIX_EPOCH).unwrap_or_default();
+                format!("{}.{:09}", elapsed.as_secs(), elapsed.subsec_nanos())
+            }
+            Self::Relative(duration) => format!("{}ms", duration.as_millis()),
+        }
+    }
+}
+
+impl From<SystemTime> for LogsSince {
+    fn from(value: SystemTime) -> Self {
+        Self::Time(value)
+    }
+}
+
+impl From<Duration> for LogsSince {
+    fn from(value: Duration) -> Self {
+        Self::Relative(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::check;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::time(
+        LogsSince::Time(UNIX_EPOCH + Duration::from_millis(1_700_000_000_250)),
+        "1700000000.250000000"
+    )]
+    #[case::relative(LogsSince::Relative(Duration::from_secs(90)), "90000ms")]
+    fn should_render_since_arg(#[case] since: LogsSince, #[case] expected: &str) {
+        check!(since.to_arg() == expected);
+    }
+}
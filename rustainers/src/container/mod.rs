@@ -2,11 +2,12 @@ use std::fmt::{self, Debug, Display};
 use std::ops::Deref;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+use std::time::{Duration, SystemTime};
 
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
-use crate::runner::Runner;
-use crate::ImageReference;
+use crate::runner::{Runner, RunnerError};
+use crate::{ExposedPort, ImageReference, Port, PortError};
 
 mod id;
 pub use self::id::*;
@@ -17,8 +18,14 @@ pub use self::health_check::*;
 mod runnable;
 pub use self::runnable::*;
 
+mod build;
+pub use self::build::*;
+
+mod logs;
+pub use self::logs::LogsSince;
+
 mod process;
-pub(crate) use self::process::ContainerProcess;
+pub use self::process::{ContainerProcess, PublishedPort};
 
 mod wait_condition;
 pub use self::wait_condition::*;
@@ -27,20 +34,41 @@ mod network;
 pub use self::network::*;
 
 mod health;
-pub(crate) use self::health::ContainerHealth;
+pub use self::health::ContainerHealth;
 
 mod state;
 pub use self::state::*;
 
+mod stats;
+pub use self::stats::ContainerStats;
+pub(crate) use self::stats::{parse_stats_line, STATS_FORMAT};
+
 mod volume;
 pub use self::volume::*;
 
+mod guard;
+pub use self::guard::ContainerGuard;
+
+mod config;
+pub use self::config::ContainerConfig;
+
+mod inspect;
+pub(crate) use self::inspect::ContainerInspect;
+
+mod exec;
+pub use self::exec::ExecOutput;
+
+/// Default timeout to wait for a container removal (10s)
+pub const WAIT_REMOVED_DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
 /// A running container
 ///
-/// It implements [`std::ops::Deref`] for the image.
+/// It implements [`std::ops::Deref`] for the image,
+/// see also [`Container::image`], and [`Container::image_mut`].
 ///
 /// When it's dropped, by default it's stopping the container,
-/// but you can choose to keep alive this container by calling [`Container::detach`](Self::detach)
+/// but you can choose to keep alive this container by calling [`Container::detach`](Self::detach),
+/// or only on failure with [`RunOptionBuilder::with_keep_on_failure`](crate::runner::RunOptionBuilder::with_keep_on_failure)
 #[derive(Debug)]
 pub struct Container<I>
 where
@@ -50,8 +78,19 @@ where
     pub(crate) id: ContainerId,
     pub(crate) image: I,
     pub(crate) image_ref: ImageReference,
+    pub(crate) port_mappings: Vec<ExposedPort>,
+    /// Keep the volumes alive (e.g. temporary files) while the container is running
+    pub(crate) _volumes: Vec<Volume>,
+    /// The time to start the container, and wait until it's ready
+    pub(crate) startup_duration: Duration,
 
     pub(crate) detached: Arc<AtomicBool>,
+
+    /// Keep the container alive if it's dropped while panicking, see [`RunOption`](crate::runner::RunOption)
+    pub(crate) keep_on_failure: bool,
+
+    /// Already stopped, see [`ContainerGuard::cleanup`], and [`Runner::stop_all`]
+    pub(crate) stopped: Arc<AtomicBool>,
 }
 
 impl<I> Container<I>
@@ -63,6 +102,187 @@ where
         self.id
     }
 
+    /// The image
+    ///
+    /// It's the same as the [`std::ops::Deref`] implementation.
+    #[must_use]
+    pub fn image(&self) -> &I {
+        &self.image
+    }
+
+    /// The mutable image, e.g. to update a state cached by the image after the start
+    ///
+    /// Note that the container is already running,
+    /// mutating the image does not change the applied run configuration
+    /// (e.g. the environment variables, the command, or the port mappings).
+    pub fn image_mut(&mut self) -> &mut I {
+        &mut self.image
+    }
+
+    /// The startup duration
+    ///
+    /// It's the time to create or restart the container,
+    /// and to wait until the container is ready, see [`WaitStrategy`].
+    #[must_use]
+    pub fn startup_duration(&self) -> Duration {
+        self.startup_duration
+    }
+
+    /// Find the host port for a container port
+    ///
+    /// # Errors
+    ///
+    /// Fail if there is no mapping with the container port
+    /// Could fail if the port is not bind
+    pub async fn host_port(&self, container_port: impl Into<Port>) -> Result<Port, PortError> {
+        let container_port = container_port.into();
+
+        for mapping in &self.port_mappings {
+            if mapping.container_port == container_port {
+                return mapping.host_port().await;
+            }
+        }
+
+        Err(PortError::ContainerPortNotFound(container_port))
+    }
+
+    /// Inspect the container, and return the raw JSON
+    ///
+    /// # Errors
+    ///
+    /// Could fail if we cannot execute the inspect command
+    pub async fn inspect_raw(&self) -> Result<serde_json::Value, RunnerError> {
+        self.runner.inspect_raw(self).await
+    }
+
+    /// Retrieve the last log lines
+    ///
+    /// See [`Runner::logs_tail`]
+    ///
+    /// # Errors
+    ///
+    /// Could fail if we cannot execute the logs command
+    pub async fn logs_tail(&self, lines: usize) -> Result<String, RunnerError> {
+        self.runner.logs_tail(self, lines).await
+    }
+
+    /// Retrieve the log lines produced since a time, or during the last duration
+    ///
+    /// See [`Runner::logs_since`]
+    ///
+    /// # Errors
+    ///
+    /// Could fail if we cannot execute the logs command
+    pub async fn logs_since(&self, since: impl Into<LogsSince>) -> Result<String, RunnerError> {
+        self.runner.logs_since(self, since).await
+    }
+
+    /// Read a file of the container
+    ///
+    /// See [`Runner::read_file`]
+    ///
+    /// # Errors
+    ///
+    /// Fail if the file does not exist
+    /// Could fail if we cannot execute the command
+    pub async fn read_file(&self, path: impl AsRef<str>) -> Result<Vec<u8>, RunnerError> {
+        self.runner.read_file(self, path).await
+    }
+
+    /// Write a file into the container
+    ///
+    /// See [`Runner::write_file`]
+    ///
+    /// # Errors
+    ///
+    /// Fail if the parent directory does not exist
+    /// Could fail if we cannot execute the command
+    pub async fn write_file(
+        &self,
+        path: impl AsRef<str>,
+        content: &[u8],
+    ) -> Result<(), RunnerError> {
+        self.runner.write_file(self, path, content).await
+    }
+
+    /// The container configuration, e.g. the effective environment variables, or labels
+    ///
+    /// See [`Runner::container_config`]
+    ///
+    /// # Errors
+    ///
+    /// Could fail if we cannot execute the inspect command
+    pub async fn container_config(&self) -> Result<ContainerConfig, RunnerError> {
+        self.runner.container_config(self).await
+    }
+
+    /// The container creation time
+    ///
+    /// # Errors
+    ///
+    /// Could fail if we cannot execute the inspect command
+    pub async fn created_at(&self) -> Result<SystemTime, RunnerError> {
+        self.runner.created_at(self).await
+    }
+
+    /// The health check log
+    ///
+    /// See [`Runner::health_log`]
+    ///
+    /// # Errors
+    ///
+    /// Could fail if we cannot execute the inspect command
+    pub async fn health_log(&self) -> Result<Vec<HealthLogEntry>, RunnerError> {
+        self.runner.health_log(self).await
+    }
+
+    /// The container (last) start time
+    ///
+    /// # Errors
+    ///
+    /// Could fail if we cannot execute the inspect command
+    pub async fn started_at(&self) -> Result<SystemTime, RunnerError> {
+        self.runner.started_at(self).await
+    }
+
+    /// The container exit code, `None` while the container has not exited
+    ///
+    /// It's useful for one-shot containers (e.g. a job, or a migration) to check the outcome.
+    ///
+    /// # Errors
+    ///
+    /// Could fail if we cannot execute the inspect command
+    pub async fn exit_code(&self) -> Result<Option<i32>, RunnerError> {
+        self.runner.exit_code(self).await
+    }
+
+    /// The container uptime, i.e. the elapsed time since the container (last) start
+    ///
+    /// # Errors
+    ///
+    /// Could fail if we cannot execute the inspect command
+    pub async fn uptime(&self) -> Result<Duration, RunnerError> {
+        let started_at = self.started_at().await?;
+        let uptime = SystemTime::now()
+            .duration_since(started_at)
+            .unwrap_or_default();
+        Ok(uptime)
+    }
+
+    /// Wait until the container is removed, e.g. after a stop with the `remove` option
+    ///
+    /// It waits at most [`WAIT_REMOVED_DEFAULT_TIMEOUT`], see [`Runner::wait_removed`]
+    ///
+    /// # Errors
+    ///
+    /// Fail if the container still exists after the timeout
+    /// Could fail if we cannot execute the ps command
+    pub async fn wait_removed(&self) -> Result<(), RunnerError> {
+        self.runner
+            .wait_removed(self, WAIT_REMOVED_DEFAULT_TIMEOUT)
+            .await
+    }
+
     /// Detach the container
     ///
     /// A detached container won't be stopped during the drop.
@@ -87,11 +307,18 @@ where
     I: ToRunnableContainer,
 {
     fn drop(&mut self) {
+        if self.stopped.swap(true, Ordering::AcqRel) {
+            return;
+        }
         let detached = self.detached.load(Ordering::Acquire);
         if detached {
             info!("Detached container {self} is NOT stopped");
             return;
         }
+        if self.keep_on_failure && std::thread::panicking() {
+            warn!("Container {self} is kept alive for debugging after a failure, remember to remove it");
+            return;
+        }
 
         info!("🚮 Stopping container");
         if let Err(err) = self.runner.stop(self) {
@@ -1,8 +1,16 @@
-use serde::{Deserialize, Serialize};
+use std::net::IpAddr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Deserializer, Serialize};
 
 use super::{ContainerId, ContainerStatus};
+use crate::timestamp::parse_rfc3339;
+use crate::{Port, PortProtocol};
 
-/// Container process
+/// Container process, as listed by the `ps` command
+///
+/// The fields available depend on the runner,
+/// docker and podman do not provide the same `ps` output.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct ContainerProcess {
@@ -10,6 +18,79 @@ pub struct ContainerProcess {
     pub(crate) id: ContainerId,
     pub(crate) names: Names,
     pub(crate) state: ContainerStatus,
+    #[serde(default)]
+    pub(crate) image: String,
+    #[serde(default)]
+    pub(crate) status: String,
+    /// Docker provides a date, podman a human readable duration
+    #[serde(default)]
+    pub(crate) created_at: Option<String>,
+    /// Podman provides a timestamp (seconds)
+    #[serde(default)]
+    pub(crate) created: Option<u64>,
+    #[serde(default, deserialize_with = "deserialize_ports", skip_serializing)]
+    pub(crate) ports: Vec<PublishedPort>,
+}
+
+impl ContainerProcess {
+    /// The container id
+    #[must_use]
+    pub fn id(&self) -> ContainerId {
+        self.id
+    }
+
+    /// The container names
+    #[must_use]
+    pub fn names(&self) -> &[String] {
+        self.names.as_slice()
+    }
+
+    /// The container state
+    #[must_use]
+    pub fn state(&self) -> ContainerStatus {
+        self.state
+    }
+
+    /// The image reference, e.g. `docker.io/library/redis:latest`
+    #[must_use]
+    pub fn image(&self) -> &str {
+        &self.image
+    }
+
+    /// The human readable status, e.g. `Up 2 hours`
+    #[must_use]
+    pub fn status(&self) -> &str {
+        &self.status
+    }
+
+    /// The container creation time
+    #[must_use]
+    pub fn created_at(&self) -> Option<SystemTime> {
+        if let Some(created) = self.created {
+            return UNIX_EPOCH.checked_add(Duration::from_secs(created));
+        }
+        self.created_at.as_deref().and_then(parse_docker_date)
+    }
+
+    /// The published ports
+    #[must_use]
+    pub fn ports(&self) -> &[PublishedPort] {
+        &self.ports
+    }
+}
+
+/// A published port of a [`ContainerProcess`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct PublishedPort {
+    /// The host IP, if the port is published
+    pub host_ip: Option<IpAddr>,
+    /// The host port, if the port is published
+    pub host_port: Option<Port>,
+    /// The container port
+    pub container_port: Port,
+    /// The protocol
+    pub protocol: PortProtocol,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -26,13 +107,115 @@ impl Names {
             Self::List(ns) => ns.iter().any(|n| n == name),
         }
     }
+
+    fn as_slice(&self) -> &[String] {
+        match self {
+            Self::Name(n) => std::slice::from_ref(n),
+            Self::List(ns) => ns,
+        }
+    }
+}
+
+/// Docker provides the ports as a text, e.g. `0.0.0.0:32768->6379/tcp, :::32768->6379/tcp`,
+/// podman provides a list of objects.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum RawPorts {
+    Text(String),
+    List(Vec<PodmanPort>),
+}
+
+#[derive(Debug, Deserialize)]
+struct PodmanPort {
+    #[serde(default)]
+    host_ip: String,
+    container_port: u16,
+    #[serde(default)]
+    host_port: Option<u16>,
+    #[serde(default)]
+    protocol: String,
+}
+
+fn deserialize_ports<'de, D>(deserializer: D) -> Result<Vec<PublishedPort>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let result = match Option::<RawPorts>::deserialize(deserializer)? {
+        None => vec![],
+        Some(RawPorts::Text(text)) => text
+            .split(',')
+            .map(str::trim)
+            .filter(|port| !port.is_empty())
+            .filter_map(parse_docker_port)
+            .collect(),
+        Some(RawPorts::List(ports)) => ports
+            .into_iter()
+            .filter_map(|port| {
+                Some(PublishedPort {
+                    host_ip: port.host_ip.parse().ok(),
+                    host_port: port.host_port.filter(|port| *port != 0).map(Port::new),
+                    container_port: Port::new(port.container_port),
+                    protocol: parse_protocol(&port.protocol)?,
+                })
+            })
+            .collect(),
+    };
+    Ok(result)
+}
+
+// Parse `0.0.0.0:32768->6379/tcp`, `:::32768->6379/tcp`, or `6379/tcp`
+fn parse_docker_port(str: &str) -> Option<PublishedPort> {
+    let (host, container) = match str.split_once("->") {
+        Some((host, container)) => (Some(host), container),
+        None => (None, str),
+    };
+    let (container_port, protocol) = container.split_once('/')?;
+    let container_port = container_port.parse().ok()?;
+    let protocol = parse_protocol(protocol)?;
+
+    let (host_ip, host_port) = if let Some(host) = host {
+        let (ip, port) = host.rsplit_once(':')?;
+        let ip = ip.trim_start_matches('[').trim_end_matches(']');
+        (ip.parse().ok(), Some(port.parse().ok()?))
+    } else {
+        (None, None)
+    };
+
+    Some(PublishedPort {
+        host_ip,
+        host_port,
+        container_port,
+        protocol,
+    })
+}
+
+fn parse_protocol(str: &str) -> Option<PortProtocol> {
+    match str {
+        "" | "tcp" => Some(PortProtocol::Tcp),
+        "udp" => Some(PortProtocol::Udp),
+        _ => None,
+    }
+}
+
+// Parse `2023-10-31 08:09:58 +0100 CET`
+fn parse_docker_date(str: &str) -> Option<SystemTime> {
+    let mut parts = str.split_whitespace();
+    let date = parts.next()?;
+    let time = parts.next()?;
+    let offset = parts.next()?;
+    let hours = offset.get(..3)?;
+    let minutes = offset.get(3..)?;
+    parse_rfc3339(&format!("{date}T{time}{hours}:{minutes}"))
 }
 
 #[cfg(test)]
 mod tests {
-    use assert2::let_assert;
+    use assert2::{check, let_assert};
+    use rstest::rstest;
 
     use super::*;
+    use crate::cmd::parse_json_list;
+
     #[test]
     fn should_serde_docker_process() {
         let json_stream = include_str!("../../tests/assets/docker-ps.jsonl");
@@ -43,6 +226,32 @@ mod tests {
         insta::assert_debug_snapshot!(data);
     }
 
+    #[test]
+    fn should_parse_docker_process_array_or_lines() {
+        let json_stream = include_str!("../../tests/assets/docker-ps.jsonl");
+        let json_array = include_str!("../../tests/assets/docker-ps.json");
+        let_assert!(Ok(lines) = parse_json_list::<ContainerProcess>(json_stream.as_bytes()));
+        let_assert!(Ok(array) = parse_json_list::<ContainerProcess>(json_array.as_bytes()));
+        check!(!lines.is_empty());
+        check!(format!("{lines:?}") == format!("{array:?}"));
+    }
+
+    #[test]
+    fn should_parse_podman_process_array() {
+        let json = include_str!("../../tests/assets/podman_ps.json");
+        let result = parse_json_list::<ContainerProcess>(json.as_bytes());
+        let_assert!(Ok(data) = result);
+        check!(!data.is_empty());
+    }
+
+    #[test]
+    fn should_parse_empty_process_list() {
+        let_assert!(Ok(lines) = parse_json_list::<ContainerProcess>(b""));
+        check!(lines.is_empty());
+        let_assert!(Ok(array) = parse_json_list::<ContainerProcess>(b" []\n"));
+        check!(array.is_empty());
+    }
+
     #[test]
     fn should_serde_podman_process() {
         let json = include_str!("../../tests/assets/podman_ps.json");
@@ -50,4 +259,36 @@ mod tests {
         let_assert!(Ok(data) = result);
         insta::assert_debug_snapshot!(data);
     }
+
+    #[rstest]
+    #[case::ipv4(
+        "0.0.0.0:32768->6379/tcp",
+        Some("0.0.0.0"),
+        Some(32768),
+        6379,
+        PortProtocol::Tcp
+    )]
+    #[case::ipv6(":::32768->53/udp", Some("::"), Some(32768), 53, PortProtocol::Udp)]
+    #[case::not_published("9092/tcp", None, None, 9092, PortProtocol::Tcp)]
+    fn should_parse_docker_port(
+        #[case] str: &str,
+        #[case] host_ip: Option<&str>,
+        #[case] host_port: Option<u16>,
+        #[case] container_port: u16,
+        #[case] protocol: PortProtocol,
+    ) {
+        let result = parse_docker_port(str);
+        let_assert!(Some(port) = result);
+        check!(port.host_ip == host_ip.and_then(|ip| ip.parse().ok()));
+        check!(port.host_port == host_port.map(Port::new));
+        check!(port.container_port == Port::new(container_port));
+        check!(port.protocol == protocol);
+    }
+
+    #[test]
+    fn should_parse_docker_date() {
+        let result = parse_docker_date("2023-10-31 08:09:58 +0100 CET");
+        let_assert!(Some(time) = result);
+        check!(time == UNIX_EPOCH + Duration::from_secs(1_698_736_198));
+    }
 }
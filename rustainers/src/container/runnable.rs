@@ -3,7 +3,10 @@ use std::fmt::{self, Display};
 use indexmap::IndexMap;
 use typed_builder::TypedBuilder;
 
-use crate::{ExposedPort, ImageReference, WaitStrategy};
+use crate::{BuildContext, ExposedPort, ImageReference, Volume, WaitStrategy};
+
+/// The wait strategies used when the image does not provide any strategy
+const DEFAULT_WAIT_STRATEGIES: &[WaitStrategy] = &[WaitStrategy::HealthCheck];
 
 /// Contains configuration require to create and run a container
 ///
@@ -47,13 +50,29 @@ pub struct RunnableContainer {
     #[builder(default, setter(transform = |args: impl IntoIterator<Item = (impl Into<String>, impl Into<String>)>| args.into_iter().map(|(key, value)| (key.into(), value.into())).collect()))]
     pub(crate) env: IndexMap<String, String>,
 
-    /// The wait strategy
-    #[builder(default, setter(into))]
-    pub(crate) wait_strategy: WaitStrategy,
+    /// The wait strategies
+    #[builder(via_mutators(init = Vec::new()), mutators(
+        /// Add a wait strategy, could be called multiple times
+        ///
+        /// The container is ready once all the strategies are satisfied, they are checked in order.
+        /// Without strategy, the image health check is used, see [`WaitStrategy::HealthCheck`].
+        pub fn with_wait_strategy(&mut self, wait_strategy: impl Into<WaitStrategy>) {
+            self.wait_strategies.push(wait_strategy.into());
+        }
+    ))]
+    pub(crate) wait_strategies: Vec<WaitStrategy>,
 
     /// The ports mapping
     #[builder(default, setter(transform = |args: impl IntoIterator<Item = ExposedPort>| args.into_iter().collect()))]
     pub(crate) port_mappings: Vec<ExposedPort>,
+
+    /// The volumes
+    #[builder(default, setter(transform = |args: impl IntoIterator<Item = impl Into<Volume>>| args.into_iter().map(Into::into).collect()))]
+    pub(crate) volumes: Vec<Volume>,
+
+    /// The build context, if the image should be built before starting the container
+    #[builder(default, setter(strip_option))]
+    pub(crate) build: Option<BuildContext>,
 }
 
 impl RunnableContainer {
@@ -62,6 +81,18 @@ impl RunnableContainer {
     pub fn descriptor(&self) -> String {
         self.image.to_string()
     }
+
+    /// The wait strategies
+    ///
+    /// Without strategy, it's the image health check, see [`WaitStrategy::HealthCheck`].
+    #[must_use]
+    pub fn wait_strategies(&self) -> &[WaitStrategy] {
+        if self.wait_strategies.is_empty() {
+            DEFAULT_WAIT_STRATEGIES
+        } else {
+            &self.wait_strategies
+        }
+    }
 }
 
 impl Display for RunnableContainer {
@@ -74,9 +105,49 @@ impl Display for RunnableContainer {
 ///
 /// A container image should implement this trait.
 /// See [`crate::images`] for usage.
-// TODO implement this trait for a single docker file with build
 // TODO derive macro?
 pub trait ToRunnableContainer {
     /// Build the runnable container
     fn to_runnable(&self, builder: RunnableContainerBuilder) -> RunnableContainer;
 }
+
+#[cfg(test)]
+mod tests {
+    use assert2::check;
+
+    use super::*;
+    use crate::{ImageName, Port};
+
+    #[test]
+    fn should_use_health_check_by_default() {
+        let runnable = RunnableContainer::builder()
+            .with_image(ImageName::new("docker.io/redis"))
+            .build();
+        check!(matches!(
+            runnable.wait_strategies(),
+            [WaitStrategy::HealthCheck]
+        ));
+    }
+
+    #[test]
+    fn should_keep_wait_strategies_order() {
+        let runnable = RunnableContainer::builder()
+            .with_image(ImageName::new("docker.io/minio/minio"))
+            .with_wait_strategy(WaitStrategy::scan_port(9000))
+            .with_wait_strategy(WaitStrategy::scan_port(9001))
+            .build();
+        check!(matches!(
+            runnable.wait_strategies(),
+            [
+                WaitStrategy::ScanPort {
+                    container_port: Port(9000),
+                    ..
+                },
+                WaitStrategy::ScanPort {
+                    container_port: Port(9001),
+                    ..
+                }
+            ]
+        ));
+    }
+}
@@ -1,8 +1,10 @@
 use std::fmt::Display;
+use std::time::SystemTime;
 
 use serde::{Deserialize, Serialize};
 
 use super::ContainerHealth;
+use crate::timestamp::parse_rfc3339;
 
 /// The container State
 #[derive(
@@ -39,6 +41,37 @@ pub enum ContainerStatus {
     Removing,
 }
 
+impl ContainerStatus {
+    /// If the container is running, or restarting
+    ///
+    /// ```rust
+    /// # use rustainers::ContainerStatus;
+    /// assert!(ContainerStatus::Running.is_running());
+    /// assert!(!ContainerStatus::Paused.is_running());
+    /// ```
+    #[must_use]
+    pub fn is_running(self) -> bool {
+        matches!(self, Self::Running | Self::Restarting)
+    }
+
+    /// If the container cannot run anymore, it needs to be created again
+    ///
+    /// A dead container, or a container being removed, is terminal.
+    /// Note that an exited container is not terminal, it could be started again.
+    #[must_use]
+    pub fn is_terminal(self) -> bool {
+        matches!(self, Self::Dead | Self::Removing)
+    }
+
+    /// If the container exists but is not running, and could be started
+    ///
+    /// Note that a paused container does not need to be started, but resumed (unpause).
+    #[must_use]
+    pub fn needs_start(self) -> bool {
+        matches!(self, Self::Created | Self::Exited | Self::Stopped)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub(crate) struct ContainerState {
@@ -46,6 +79,22 @@ pub(crate) struct ContainerState {
     pub(crate) status: ContainerStatus,
     #[serde(default)]
     pub(crate) health: ContainerFullStateHealth,
+    #[serde(default)]
+    pub(crate) started_at: Option<String>,
+    #[serde(default)]
+    pub(crate) exit_code: Option<i32>,
+}
+
+impl ContainerState {
+    /// The exit code, only available once the container has exited
+    pub(crate) fn exit_code(&self) -> Option<i32> {
+        matches!(
+            self.status,
+            ContainerStatus::Exited | ContainerStatus::Stopped | ContainerStatus::Dead
+        )
+        .then_some(self.exit_code)
+        .flatten()
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
@@ -55,21 +104,48 @@ pub(crate) struct ContainerFullStateHealth {
     #[serde(default)]
     failing_streak: usize,
     #[serde(default)]
-    pub(crate) log: Option<Vec<HealthCheckLog>>,
+    pub(crate) log: Option<Vec<HealthLogEntry>>,
 }
 
+/// A health check log entry, i.e. the result of a health check probe
 #[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
-pub(crate) struct HealthCheckLog {
+pub struct HealthLogEntry {
     start: String,
     end: String,
     exit_code: i32,
     output: String,
 }
 
-impl Display for HealthCheckLog {
+impl HealthLogEntry {
+    /// The probe start time
+    #[must_use]
+    pub fn start(&self) -> Option<SystemTime> {
+        parse_rfc3339(&self.start)
+    }
+
+    /// The probe end time
+    #[must_use]
+    pub fn end(&self) -> Option<SystemTime> {
+        parse_rfc3339(&self.end)
+    }
+
+    /// The probe exit code, `0` when healthy
+    #[must_use]
+    pub fn exit_code(&self) -> i32 {
+        self.exit_code
+    }
+
+    /// The probe output
+    #[must_use]
+    pub fn output(&self) -> &str {
+        &self.output
+    }
+}
+
+impl Display for HealthLogEntry {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let HealthCheckLog {
+        let HealthLogEntry {
             start,
             end,
             exit_code,
@@ -81,9 +157,34 @@ impl Display for HealthCheckLog {
 
 #[cfg(test)]
 mod tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use assert2::{check, let_assert};
+    use rstest::rstest;
 
     use super::*;
 
+    #[rstest]
+    #[case::unknown(ContainerStatus::Unknown, false, false, false)]
+    #[case::created(ContainerStatus::Created, false, false, true)]
+    #[case::running(ContainerStatus::Running, true, false, false)]
+    #[case::restarting(ContainerStatus::Restarting, true, false, false)]
+    #[case::stopped(ContainerStatus::Stopped, false, false, true)]
+    #[case::exited(ContainerStatus::Exited, false, false, true)]
+    #[case::paused(ContainerStatus::Paused, false, false, false)]
+    #[case::dead(ContainerStatus::Dead, false, true, false)]
+    #[case::removing(ContainerStatus::Removing, false, true, false)]
+    fn should_classify_status(
+        #[case] status: ContainerStatus,
+        #[case] running: bool,
+        #[case] terminal: bool,
+        #[case] start: bool,
+    ) {
+        check!(status.is_running() == running);
+        check!(status.is_terminal() == terminal);
+        check!(status.needs_start() == start);
+    }
+
     #[test]
     fn should_serde_inspect_status() {
         let json = include_str!("../../tests/assets/inspect-state.json");
@@ -97,4 +198,49 @@ mod tests {
         let result = serde_json::from_str::<ContainerState>(json).expect("container state");
         insta::assert_debug_snapshot!(result);
     }
+
+    #[test]
+    fn should_parse_started_at() {
+        let json = include_str!("../../tests/assets/inspect-state-exited.json");
+        let result = serde_json::from_str::<ContainerState>(json).expect("container state");
+        let_assert!(Some(started_at) = result.started_at.as_deref().and_then(parse_rfc3339));
+        check!(started_at == UNIX_EPOCH + Duration::new(1_698_741_134, 261_437_625));
+    }
+
+    #[test]
+    fn should_serde_exit_code() {
+        let json = include_str!("../../tests/assets/inspect-state-exited-error.json");
+        let result = serde_json::from_str::<ContainerState>(json).expect("container state");
+        check!(result.status == ContainerStatus::Exited);
+        check!(result.exit_code == Some(137));
+        check!(result.exit_code() == Some(137));
+    }
+
+    #[test]
+    fn should_not_provide_exit_code_while_running() {
+        let json = include_str!("../../tests/assets/inspect-state.json");
+        let result = serde_json::from_str::<ContainerState>(json).expect("container state");
+        check!(result.exit_code == Some(0));
+        check!(result.exit_code().is_none());
+    }
+
+    #[test]
+    fn should_serde_missing_exit_code() {
+        let json = r#"{"Status": "exited"}"#;
+        let result = serde_json::from_str::<ContainerState>(json).expect("container state");
+        check!(result.exit_code.is_none());
+    }
+
+    #[test]
+    fn should_serde_health_log() {
+        let json = include_str!("../../tests/assets/inspect-state-unhealthy.json");
+        let result = serde_json::from_str::<ContainerState>(json).expect("container state");
+        check!(result.health.status == ContainerHealth::Unhealthy);
+        let_assert!(Some([first, last]) = result.health.log.as_deref());
+        check!(first.exit_code() == 1);
+        check!(last.output() == "curl: (7) Failed to connect to localhost port 80\n");
+        let_assert!(Some(start) = last.start());
+        let_assert!(Some(end) = last.end());
+        check!(start <= end);
+    }
 }
@@ -0,0 +1,131 @@
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// The `stats` command format, it's a JSON object per line
+///
+/// The fields are common to docker, podman, and nerdctl.
+pub(crate) const STATS_FORMAT: &str = r#"{"name":{{json .Name}},"cpu":{{json .CPUPerc}},"memory_usage":{{json .MemUsage}},"memory":{{json .MemPerc}},"net_io":{{json .NetIO}},"block_io":{{json .BlockIO}},"pids":{{json .PIDs}}}"#;
+
+/// A container resource usage sample, as provided by the `stats` command
+///
+/// The values are human readable, as displayed by the runner,
+/// except the percentages, and the number of processes.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContainerStats {
+    pub(crate) name: String,
+    #[serde(deserialize_with = "deserialize_percent")]
+    pub(crate) cpu: Option<f64>,
+    pub(crate) memory_usage: String,
+    #[serde(deserialize_with = "deserialize_percent")]
+    pub(crate) memory: Option<f64>,
+    pub(crate) net_io: String,
+    pub(crate) block_io: String,
+    #[serde(deserialize_with = "deserialize_pids")]
+    pub(crate) pids: Option<u64>,
+}
+
+impl ContainerStats {
+    /// The container name
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The CPU usage percentage, e.g. `12.5` for `12.5%`
+    ///
+    /// Note that it could exceed `100` with multiple CPUs.
+    #[must_use]
+    pub fn cpu_percent(&self) -> Option<f64> {
+        self.cpu
+    }
+
+    /// The memory usage and limit, e.g. `3.5MiB / 7.6GiB`
+    #[must_use]
+    pub fn memory_usage(&self) -> &str {
+        &self.memory_usage
+    }
+
+    /// The memory usage percentage, e.g. `0.05` for `0.05%`
+    #[must_use]
+    pub fn memory_percent(&self) -> Option<f64> {
+        self.memory
+    }
+
+    /// The network I/O, e.g. `1.2kB / 0B`
+    #[must_use]
+    pub fn net_io(&self) -> &str {
+        &self.net_io
+    }
+
+    /// The block I/O, e.g. `0B / 4.1kB`
+    #[must_use]
+    pub fn block_io(&self) -> &str {
+        &self.block_io
+    }
+
+    /// The number of processes
+    #[must_use]
+    pub fn pids(&self) -> Option<u64> {
+        self.pids
+    }
+}
+
+/// The percentage looks like `12.34%`, or `--` when not available
+fn deserialize_percent<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = String::deserialize(deserializer)?;
+    let result = value.trim().trim_end_matches('%').parse().ok();
+    Ok(result)
+}
+
+/// The number of processes is a string, or `--` when not available
+fn deserialize_pids<'de, D>(deserializer: D) -> Result<Option<u64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = String::deserialize(deserializer)?;
+    let result = value.trim().parse().ok();
+    Ok(result)
+}
+
+/// Parse a `stats` line
+///
+/// In streaming mode, the runner could prefix the line with terminal escape sequences
+pub(crate) fn parse_stats_line(line: &str) -> Option<Result<ContainerStats, serde_json::Error>> {
+    let start = line.find('{')?;
+    let result = serde_json::from_str(&line[start..]);
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+
+    use super::*;
+
+    #[test]
+    fn should_parse_stats_line() {
+        let line = "\u{1b}[2J\u{1b}[H{\"name\":\"redis\",\"cpu\":\"12.50%\",\"memory_usage\":\"3.5MiB / 7.6GiB\",\"memory\":\"0.05%\",\"net_io\":\"1.2kB / 0B\",\"block_io\":\"0B / 4.1kB\",\"pids\":\"5\"}";
+        let_assert!(Some(Ok(stats)) = parse_stats_line(line));
+        check!(stats.name() == "redis");
+        check!(stats.cpu_percent() == Some(12.5));
+        check!(stats.memory_usage() == "3.5MiB / 7.6GiB");
+        check!(stats.memory_percent() == Some(0.05));
+        check!(stats.pids() == Some(5));
+    }
+
+    #[test]
+    fn should_parse_unavailable_stats() {
+        let line = r#"{"name":"redis","cpu":"--","memory_usage":"-- / --","memory":"--","net_io":"-- / --","block_io":"-- / --","pids":"--"}"#;
+        let_assert!(Some(Ok(stats)) = parse_stats_line(line));
+        check!(stats.cpu_percent().is_none());
+        check!(stats.memory_percent().is_none());
+        check!(stats.pids().is_none());
+    }
+
+    #[test]
+    fn should_skip_line_without_json() {
+        check!(parse_stats_line("\u{1b}[2J\u{1b}[H").is_none());
+    }
+}
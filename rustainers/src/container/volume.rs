@@ -1,8 +1,11 @@
 use std::fmt::Display;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::sync::Arc;
 
 use path_absolutize::Absolutize;
+use tracing::warn;
+use ulid::Ulid;
 
 use crate::VolumeError;
 
@@ -49,6 +52,10 @@ impl Display for VolumeName {
 /// // Create a tmpfs volume (in-memory)
 /// let v = Volume::tmpfs("/etc/var/data");
 /// assert!(matches!(v, Volume::Tmpfs{..}));
+///
+/// // Create a read-only bind mount of a content
+/// let v = Volume::bind_content(b"plop".to_vec(), "/etc/plop.conf").expect("a temporary file");
+/// assert!(matches!(v, Volume::BindContent{..}));
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Volume {
@@ -76,6 +83,17 @@ pub enum Volume {
         readonly: bool,
     },
 
+    /// A read-only bind mount of a content
+    ///
+    /// The content is written into a temporary file,
+    /// this file is removed when the last clone of this volume is dropped.
+    BindContent {
+        /// The container path (target)
+        container: PathBuf,
+        /// The temporary file (source)
+        file: Arc<TemporaryContentFile>,
+    },
+
     /// In-Memory mount
     ///
     /// ⚠️ WARNING, this is not supported by all platform
@@ -108,6 +126,25 @@ impl Volume {
         }
     }
 
+    /// Create a read-only bind mount volume of a content
+    ///
+    /// The content is written into a temporary file.
+    ///
+    /// # Errors
+    ///
+    /// Fail if we cannot write the temporary file
+    pub fn bind_content(
+        content: impl AsRef<[u8]>,
+        container: impl Into<PathBuf>,
+    ) -> Result<Self, VolumeError> {
+        let file = TemporaryContentFile::new(content.as_ref())?;
+        let container = container.into();
+        Ok(Self::BindContent {
+            container,
+            file: Arc::new(file),
+        })
+    }
+
     /// Create a tmpfs volume
     pub fn tmpfs(container: impl Into<PathBuf>) -> Self {
         let container = container.into();
@@ -116,13 +153,13 @@ impl Volume {
 
     /// Make the volume readonly
     ///
-    /// ⚠️ WARNING this is not supported by `Tmpfs`
+    /// ⚠️ WARNING this is not supported by `Tmpfs`, and `BindContent` is always readonly
     pub fn read_only(&mut self) {
         match self {
             Self::ContainerVolume { readonly, .. } | Self::BindMount { readonly, .. } => {
                 *readonly = true;
             }
-            Self::Tmpfs { .. } => {}
+            Self::BindContent { .. } | Self::Tmpfs { .. } => {}
         }
     }
 
@@ -154,6 +191,14 @@ impl Volume {
                     trg.to_string_lossy()
                 )
             }
+            Self::BindContent { container, file } => {
+                let trg = container.absolutize()?;
+                format!(
+                    "type=bind,source={},target={},readonly",
+                    file.0.to_string_lossy(),
+                    trg.to_string_lossy()
+                )
+            }
             Self::Tmpfs { container } => {
                 let trg = container.absolutize()?;
                 format!("type=tmpfs,target={}", trg.to_string_lossy())
@@ -163,6 +208,35 @@ impl Volume {
     }
 }
 
+/// A temporary file used by a [`Volume::BindContent`]
+///
+/// The file is removed during the drop.
+#[derive(Debug, PartialEq, Eq)]
+pub struct TemporaryContentFile(PathBuf);
+
+impl TemporaryContentFile {
+    pub(crate) fn new(content: &[u8]) -> Result<Self, VolumeError> {
+        let mut path = std::env::temp_dir();
+        path.push(format!("tc_content_{}", Ulid::new()));
+        std::fs::write(&path, content)?;
+        Ok(Self(path))
+    }
+}
+
+impl AsRef<Path> for TemporaryContentFile {
+    fn as_ref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TemporaryContentFile {
+    fn drop(&mut self) {
+        if let Err(err) = std::fs::remove_file(&self.0) {
+            warn!("Fail to clean up temporary file {:?} because {err}", self.0);
+        }
+    }
+}
+
 impl<P, Q> From<(P, Q)> for Volume
 where
     P: AsRef<Path>,
@@ -184,3 +258,31 @@ where
         Self::container_volume(value.0, container)
     }
 }
+
+#[cfg(test)]
+#[allow(clippy::ignored_unit_patterns)]
+mod tests {
+    use assert2::{check, let_assert};
+
+    use super::*;
+
+    #[test]
+    fn should_bind_content() {
+        let result = Volume::bind_content(b"plop", "/etc/plop.conf");
+        let_assert!(Ok(volume) = result);
+        let_assert!(Volume::BindContent { file, .. } = &volume);
+        let path = file.as_ref().as_ref().to_path_buf();
+        let_assert!(Ok(content) = std::fs::read(&path));
+        check!(content == b"plop");
+
+        let_assert!(Ok(arg) = volume.mount_arg());
+        check!(arg.ends_with(",target=/etc/plop.conf,readonly"));
+
+        // Check the file is removed on drop
+        let cloned = volume.clone();
+        drop(volume);
+        check!(path.exists());
+        drop(cloned);
+        check!(!path.exists());
+    }
+}
@@ -1,4 +1,5 @@
 use std::fmt::Display;
+use std::path::PathBuf;
 use std::time::Duration;
 
 use crate::io::StdIoKind;
@@ -7,6 +8,9 @@ use crate::{ContainerStatus, HealthCheck, Port};
 /// Default port scan timeout (100ms)
 pub const SCAN_PORT_DEFAULT_TIMEOUT: Duration = Duration::from_millis(100);
 
+/// Default file existence check interval (100ms)
+pub const FILE_EXISTS_DEFAULT_INTERVAL: Duration = Duration::from_millis(100);
+
 /// Wait strategies
 #[derive(Debug, Clone, Default)]
 #[non_exhaustive]
@@ -22,6 +26,8 @@ pub enum WaitStrategy {
     State(ContainerStatus),
 
     /// Wait until the HTTP call provide a successful status (e.g. 200 OK)
+    ///
+    /// Each request has a short timeout (1s), then the request is retried.
     HttpSuccess {
         /// If we use HTTPS instead of HTTP
         https: bool,
@@ -36,6 +42,27 @@ pub enum WaitStrategy {
         container_port: Port,
     },
 
+    /// Wait until the HTTP call on a known host port provide a successful status (e.g. 200 OK)
+    ///
+    /// It's useful with a fixed port mapping (see [`ExposedPort::fixed`](crate::ExposedPort::fixed)),
+    /// the host port lookup is skipped, so we do not wait until the runner registers the port mapping.
+    /// See [`WaitStrategy::with_host_port`].
+    ///
+    /// Each request has a short timeout (1s), then the request is retried.
+    HttpSuccessOnHostPort {
+        /// If we use HTTPS instead of HTTP
+        https: bool,
+        /// If TLS certificates are validated.
+        ///
+        /// Setting this field to `false` will allow self-signed certificates to be used.
+        /// This setting is used only when `https` is set to `true`.
+        require_valid_certs: bool,
+        /// The path to check
+        path: String,
+        /// The host port
+        host_port: Port,
+    },
+
     /// Wait until a socket is open
     ScanPort {
         /// The container port
@@ -44,6 +71,45 @@ pub enum WaitStrategy {
         timeout: Duration,
     },
 
+    /// Wait until an UDP port answers, it's best-effort
+    ///
+    /// UDP does not have a connection handshake, so a probe datagram is sent,
+    /// and the port is considered ready if there is a response, or no error before the timeout.
+    /// Only an ICMP port unreachable (i.e. a refused connection) means the port is not ready.
+    /// Note that with the userland proxy of the runner, the port mapping could accept the datagram
+    /// before the service listens, prefer a service specific probe (e.g. a DNS query) if you need a response.
+    ScanUdpPort {
+        /// The container port
+        container_port: Port,
+        /// The timeout for a try
+        timeout: Duration,
+        /// The probe datagram
+        probe: Vec<u8>,
+    },
+
+    /// Wait until the gRPC health check succeed
+    ///
+    /// It uses the [standard gRPC health checking protocol](https://github.com/grpc/grpc/blob/master/doc/health-checking.md)
+    /// by executing the [`grpc_health_probe`](https://github.com/grpc-ecosystem/grpc-health-probe)
+    /// command inside the container, therefore this command should be available in the container.
+    Grpc {
+        /// The container port
+        container_port: Port,
+        /// The service to check, if `None` the server overall health is checked
+        service: Option<String>,
+    },
+
+    /// Wait until a file exists inside the container
+    ///
+    /// It executes the `test -f <path>` command inside the container,
+    /// therefore the `test` command should be available in the container.
+    FileExists {
+        /// The container file path
+        path: PathBuf,
+        /// The interval between two checks
+        interval: Duration,
+    },
+
     /// Wait until log match a pattern
     LogMatch {
         /// the type of io
@@ -105,6 +171,47 @@ impl WaitStrategy {
         }
     }
 
+    /// Wait for an successful HTTPS call on the 443 port, accepting invalid certificates
+    ///
+    /// Useful for test servers with a self-signed certificate.
+    pub fn https_accept_invalid_certs(path: impl Into<String>) -> Self {
+        let path = path.into();
+        let container_port = Port(443);
+        Self::HttpSuccess {
+            https: true,
+            require_valid_certs: false,
+            path,
+            container_port,
+        }
+    }
+
+    /// Use a known host port for the HTTP wait strategy
+    ///
+    /// It turns a [`WaitStrategy::HttpSuccess`] into a [`WaitStrategy::HttpSuccessOnHostPort`],
+    /// it has no effect on other wait strategies.
+    ///
+    /// ```rust
+    /// # use rustainers::WaitStrategy;
+    /// let wait = WaitStrategy::http("/health").with_host_port(8080);
+    /// ```
+    #[must_use]
+    pub fn with_host_port(self, host_port: impl Into<Port>) -> Self {
+        match self {
+            Self::HttpSuccess {
+                https,
+                require_valid_certs,
+                path,
+                ..
+            } => Self::HttpSuccessOnHostPort {
+                https,
+                require_valid_certs,
+                path,
+                host_port: host_port.into(),
+            },
+            other => other,
+        }
+    }
+
     /// Wait for a port to be open using a default timeout
     pub fn scan_port(container_port: impl Into<Port>) -> Self {
         let container_port = container_port.into();
@@ -115,6 +222,56 @@ impl WaitStrategy {
         }
     }
 
+    /// Wait for an UDP port to answer using a default timeout, and an empty probe datagram
+    ///
+    /// See [`WaitStrategy::ScanUdpPort`]
+    pub fn scan_udp_port(container_port: impl Into<Port>) -> Self {
+        Self::scan_udp_port_with_probe(container_port, [])
+    }
+
+    /// Wait for an UDP port to answer to a probe datagram using a default timeout
+    ///
+    /// See [`WaitStrategy::ScanUdpPort`]
+    pub fn scan_udp_port_with_probe(
+        container_port: impl Into<Port>,
+        probe: impl Into<Vec<u8>>,
+    ) -> Self {
+        let container_port = container_port.into();
+        let timeout = SCAN_PORT_DEFAULT_TIMEOUT;
+        let probe = probe.into();
+        Self::ScanUdpPort {
+            container_port,
+            timeout,
+            probe,
+        }
+    }
+
+    /// Wait for a successful gRPC health check of the server
+    pub fn grpc(container_port: impl Into<Port>) -> Self {
+        let container_port = container_port.into();
+        Self::Grpc {
+            container_port,
+            service: None,
+        }
+    }
+
+    /// Wait for a successful gRPC health check of a service
+    pub fn grpc_service(container_port: impl Into<Port>, service: impl Into<String>) -> Self {
+        let container_port = container_port.into();
+        let service = Some(service.into());
+        Self::Grpc {
+            container_port,
+            service,
+        }
+    }
+
+    /// Wait for a file to exist inside the container using a default interval
+    pub fn file_exists(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let interval = FILE_EXISTS_DEFAULT_INTERVAL;
+        Self::FileExists { path, interval }
+    }
+
     /// Wait for a log line in stdout contains a string
     #[must_use]
     pub fn stdout_contains(str: impl Into<String>) -> Self {
@@ -132,6 +289,15 @@ impl WaitStrategy {
             matcher: LogMatcher::Contains(str.into()),
         }
     }
+
+    /// Wait for a log line in stdout or stderr contains a string
+    #[must_use]
+    pub fn output_contains(str: impl Into<String>) -> Self {
+        Self::LogMatch {
+            io: StdIoKind::Both,
+            matcher: LogMatcher::Contains(str.into()),
+        }
+    }
 }
 
 #[cfg(feature = "regex")]
@@ -153,6 +319,15 @@ impl WaitStrategy {
             matcher: LogMatcher::Regex(Box::new(re)),
         }
     }
+
+    /// Wait for a log line in stdout or stderr match a pattern
+    #[must_use]
+    pub fn output_match(re: regex::Regex) -> Self {
+        Self::LogMatch {
+            io: StdIoKind::Both,
+            matcher: LogMatcher::Regex(Box::new(re)),
+        }
+    }
 }
 
 impl From<HealthCheck> for WaitStrategy {
@@ -181,15 +356,17 @@ impl Display for WaitStrategy {
             } => write!(
                 f,
                 "HTTP success {}on path path {path} with container port {container_port}",
-                if *https {
-                    if *require_valid_certs {
-                        "(HTTPS with valid certs)"
-                    } else {
-                        "(HTTPS with self-signed certs)"
-                    }
-                } else {
-                    ""
-                }
+                http_kind(*https, *require_valid_certs)
+            ),
+            Self::HttpSuccessOnHostPort {
+                https,
+                require_valid_certs,
+                path,
+                host_port,
+            } => write!(
+                f,
+                "HTTP success {}on path path {path} with host port {host_port}",
+                http_kind(*https, *require_valid_certs)
             ),
             Self::ScanPort {
                 container_port,
@@ -198,6 +375,27 @@ impl Display for WaitStrategy {
                 f,
                 "Container port {container_port} open (timeout {timeout:?})"
             ),
+            Self::ScanUdpPort {
+                container_port,
+                timeout,
+                ..
+            } => write!(
+                f,
+                "Container UDP port {container_port} answers (timeout {timeout:?})"
+            ),
+            Self::Grpc {
+                container_port,
+                service,
+            } => {
+                write!(f, "gRPC health check with container port {container_port}")?;
+                if let Some(service) = service {
+                    write!(f, " for service {service}")?;
+                }
+                Ok(())
+            }
+            Self::FileExists { path, interval } => {
+                write!(f, "File {} exists (interval {interval:?})", path.display())
+            }
             Self::LogMatch { io, .. } => write!(f, "Log match pattern on {io}"),
             Self::None => write!(f, "None"),
         }
@@ -225,3 +423,37 @@ impl LogMatcher {
         }
     }
 }
+
+fn http_kind(https: bool, require_valid_certs: bool) -> &'static str {
+    if https {
+        if require_valid_certs {
+            "(HTTPS with valid certs)"
+        } else {
+            "(HTTPS with self-signed certs)"
+        }
+    } else {
+        ""
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+
+    use super::*;
+
+    #[test]
+    fn should_set_http_host_port() {
+        let wait = WaitStrategy::http("/health").with_host_port(8080);
+        let_assert!(WaitStrategy::HttpSuccessOnHostPort { host_port, .. } = &wait);
+        check!(*host_port == Port(8080));
+        check!(wait.to_string() == "HTTP success on path path /health with host port 8080");
+    }
+
+    #[test]
+    fn should_ignore_host_port_for_other_strategies() {
+        let wait = WaitStrategy::scan_port(80).with_host_port(8080);
+        let_assert!(WaitStrategy::ScanPort { container_port, .. } = wait);
+        check!(container_port == Port(80));
+    }
+}
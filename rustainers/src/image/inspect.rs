@@ -0,0 +1,92 @@
+use std::collections::BTreeMap;
+
+use indexmap::IndexMap;
+use serde::Deserialize;
+
+use crate::{ContainerConfig, ExposedPort};
+
+/// The image configuration, as provided by the `image inspect` command
+///
+/// See [`Runner::inspect_image`](crate::runner::Runner::inspect_image)
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ImageInspect {
+    #[serde(default)]
+    pub(crate) config: ContainerConfig,
+}
+
+impl ImageInspect {
+    /// The image configuration
+    #[must_use]
+    pub fn config(&self) -> &ContainerConfig {
+        &self.config
+    }
+
+    /// The ports exposed by the image (`EXPOSE` instruction)
+    ///
+    /// The ports are sorted by container port,
+    /// they could be used directly as port mappings.
+    #[must_use]
+    pub fn exposed_ports(&self) -> Vec<ExposedPort> {
+        self.config.exposed_ports()
+    }
+
+    /// The default environment variables (`ENV` instruction)
+    #[must_use]
+    pub fn env(&self) -> IndexMap<String, String> {
+        self.config.env()
+    }
+
+    /// The entrypoint (`ENTRYPOINT` instruction)
+    #[must_use]
+    pub fn entrypoint(&self) -> &[String] {
+        self.config.entrypoint()
+    }
+
+    /// The default command (`CMD` instruction)
+    #[must_use]
+    pub fn cmd(&self) -> &[String] {
+        self.config.cmd()
+    }
+
+    /// The labels (`LABEL` instruction)
+    #[must_use]
+    pub fn labels(&self) -> BTreeMap<&str, &str> {
+        self.config.labels()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+
+    use super::*;
+
+    #[test]
+    fn should_serde() {
+        let json = include_str!("../../tests/assets/docker-image-inspect.json");
+        let result = serde_json::from_str::<Vec<ImageInspect>>(json);
+        let_assert!(Ok(images) = result);
+        let_assert!([image] = images.as_slice());
+
+        check!(image.exposed_ports() == [ExposedPort::new(80), ExposedPort::udp(443)]);
+        check!(image.env().get("NGINX_VERSION").map(String::as_str) == Some("1.25.4"));
+        check!(image.entrypoint() == ["/docker-entrypoint.sh"]);
+        check!(image.cmd() == ["nginx", "-g", "daemon off;"]);
+        check!(
+            image.labels().get("maintainer")
+                == Some(&"NGINX Docker Maintainers <docker-maint@nginx.com>")
+        );
+    }
+
+    #[test]
+    fn should_serde_null_config() {
+        let json = r#"{"Config":{"ExposedPorts":null,"Env":null,"Entrypoint":null,"Cmd":null,"Labels":null}}"#;
+        let result = serde_json::from_str::<ImageInspect>(json);
+        let_assert!(Ok(image) = result);
+        check!(image.exposed_ports().is_empty());
+        check!(image.env().is_empty());
+        check!(image.entrypoint().is_empty());
+        check!(image.labels().is_empty());
+    }
+}
@@ -9,3 +9,6 @@ pub use self::reference::*;
 
 mod name;
 pub use self::name::*;
+
+mod inspect;
+pub use self::inspect::*;
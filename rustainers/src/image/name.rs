@@ -84,6 +84,50 @@ impl ImageName {
     pub fn set_digest(&mut self, digest: impl Into<String>) {
         self.digest = Some(Cow::Owned(digest.into()));
     }
+
+    /// The registry, if explicitly provided in the repository
+    ///
+    /// The registry is the part before the first `/` if it contains a `.` or a `:`,
+    /// or if it's `localhost`.
+    ///
+    /// ```rust
+    /// # use rustainers::ImageName;
+    /// assert_eq!(ImageName::new("docker.io/postgres").registry(), Some("docker.io"));
+    /// assert_eq!(ImageName::new("minio/minio").registry(), None);
+    /// ```
+    #[must_use]
+    pub fn registry(&self) -> Option<&str> {
+        self.split_registry().0
+    }
+
+    /// Set or replace the registry
+    ///
+    /// An image without explicit registry (implicitly `docker.io`) gets the registry as prefix.
+    ///
+    /// ```rust
+    /// # use rustainers::ImageName;
+    /// let image = ImageName::new("docker.io/postgres").with_registry("mirror.corp");
+    /// assert_eq!(image.to_string(), "mirror.corp/postgres");
+    ///
+    /// let image = ImageName::new_with_tag("minio/minio", "latest").with_registry("localhost:5000");
+    /// assert_eq!(image.to_string(), "localhost:5000/minio/minio:latest");
+    /// ```
+    #[must_use]
+    pub fn with_registry(self, registry: &str) -> Self {
+        let registry = registry.trim_end_matches('/');
+        let (_, path) = self.split_registry();
+        let repository = Cow::Owned(format!("{registry}/{path}"));
+        Self { repository, ..self }
+    }
+
+    fn split_registry(&self) -> (Option<&str>, &str) {
+        match self.repository.split_once('/') {
+            Some((registry, path)) if registry.contains(['.', ':']) || registry == "localhost" => {
+                (Some(registry), path)
+            }
+            _ => (None, &self.repository),
+        }
+    }
 }
 
 impl Display for ImageName {
@@ -143,10 +187,42 @@ impl FromStr for ImageName {
 #[cfg(test)]
 #[allow(clippy::ignored_unit_patterns)]
 mod tests {
-    use assert2::let_assert;
+    use assert2::{check, let_assert};
+    use rstest::rstest;
 
     use super::*;
 
+    #[rstest]
+    #[case::docker_io("docker.io/postgres", "mirror.corp/postgres")]
+    #[case::bare("postgres", "mirror.corp/postgres")]
+    #[case::organization("minio/minio", "mirror.corp/minio/minio")]
+    #[case::registry_with_port("localhost:5000/redis", "mirror.corp/redis")]
+    #[case::localhost("localhost/redis", "mirror.corp/redis")]
+    fn should_replace_registry(#[case] name: &'static str, #[case] expected: &str) {
+        let image = ImageName::new(name);
+        let result = image.with_registry("mirror.corp");
+        check!(result.registry() == Some("mirror.corp"));
+        check!(result.to_string() == expected);
+    }
+
+    #[test]
+    fn should_keep_tag_and_digest_when_replacing_registry() {
+        let mut image = ImageName::new_with_tag("quay.io/coreos/etcd", "v3.5");
+        image.set_digest("sha256:1234");
+        let result = image.with_registry("mirror.corp/");
+        check!(result.to_string() == "mirror.corp/coreos/etcd:v3.5@sha256:1234");
+    }
+
+    #[rstest]
+    #[case::docker_io("docker.io/postgres", Some("docker.io"))]
+    #[case::bare("postgres", None)]
+    #[case::organization("minio/minio", None)]
+    #[case::registry_with_port("localhost:5000/redis", Some("localhost:5000"))]
+    fn should_get_registry(#[case] name: &'static str, #[case] expected: Option<&str>) {
+        let image = ImageName::new(name);
+        check!(image.registry() == expected);
+    }
+
     #[test]
     fn should_not_parse_empty_name() {
         let result = "".parse::<ImageName>();
@@ -0,0 +1,156 @@
+use crate::compose::{TempDirError, TemporaryDirectory, TemporaryFile};
+use crate::{
+    Container, ExposedPort, ImageName, Port, PortError, RunnableContainer,
+    RunnableContainerBuilder, ToRunnableContainer, Volume, WaitStrategy,
+};
+
+const COREDNS_IMAGE: &ImageName = &ImageName::new("docker.io/coredns/coredns");
+
+const PORT: Port = Port(53);
+
+const COREFILE: &str = "Corefile";
+
+/// A `CoreDNS` image
+///
+/// The DNS port is exposed with UDP and TCP.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// # async fn run() -> anyhow::Result<()> {
+/// use rustainers::images::CoreDns;
+///
+/// let default_image = CoreDns::default();
+///
+/// let custom_image = CoreDns::default()
+///     .with_tag("1.11.1")
+///     .with_corefile(".:53 {\n    whoami\n    log\n}\n")
+///     .await?;
+///
+/// # let runner = rustainers::runner::Runner::auto()?;
+/// // ...
+/// let container = runner.start(default_image).await?;
+/// let endpoint = container.dns_endpoint().await?;
+/// // ...
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct CoreDns {
+    image: ImageName,
+    udp_port: ExposedPort,
+    tcp_port: ExposedPort,
+    corefile: Option<TemporaryDirectory>,
+}
+
+impl CoreDns {
+    /// Set the image tag
+    #[must_use]
+    pub fn with_tag(self, tag: impl Into<String>) -> Self {
+        let Self { mut image, .. } = self;
+        image.set_tag(tag);
+        Self { image, ..self }
+    }
+
+    /// Set the image digest
+    #[must_use]
+    pub fn with_digest(self, digest: impl Into<String>) -> Self {
+        let Self { mut image, .. } = self;
+        image.set_digest(digest);
+        Self { image, ..self }
+    }
+
+    /// Set the UDP port mapping
+    #[must_use]
+    pub fn with_udp_port(mut self, port: ExposedPort) -> Self {
+        self.udp_port = port;
+        self
+    }
+
+    /// Set the TCP port mapping
+    #[must_use]
+    pub fn with_tcp_port(mut self, port: ExposedPort) -> Self {
+        self.tcp_port = port;
+        self
+    }
+
+    /// Set the Corefile content
+    ///
+    /// The Corefile is written into a temporary directory, and mounted into the container.
+    /// The temporary directory is removed when the image is dropped.
+    ///
+    /// # Errors
+    ///
+    /// Fail if we cannot write the Corefile
+    pub async fn with_corefile(self, content: impl AsRef<[u8]>) -> Result<Self, TempDirError> {
+        let file = TemporaryFile::builder()
+            .with_path(COREFILE)
+            .with_content(content)
+            .build();
+        let dir = TemporaryDirectory::with_files("coredns", [file]).await?;
+        Ok(Self {
+            corefile: Some(dir),
+            ..self
+        })
+    }
+}
+
+impl Default for CoreDns {
+    fn default() -> Self {
+        Self {
+            image: COREDNS_IMAGE.clone(),
+            udp_port: ExposedPort::udp(PORT),
+            tcp_port: ExposedPort::new(PORT),
+            corefile: None,
+        }
+    }
+}
+
+impl Container<CoreDns> {
+    /// Get the DNS endpoint (UDP), i.e. `{host_ip}:{port}`
+    ///
+    /// # Errors
+    ///
+    /// Could fail if the port is not bind
+    pub async fn dns_endpoint(&self) -> Result<String, PortError> {
+        let port = self.udp_port.host_port().await?;
+        let host_ip = self.runner.container_host_ip().await?;
+        let endpoint = format!("{host_ip}:{port}");
+
+        Ok(endpoint)
+    }
+
+    /// Get the DNS endpoint over TCP, i.e. `{host_ip}:{port}`
+    ///
+    /// # Errors
+    ///
+    /// Could fail if the port is not bind
+    pub async fn tcp_dns_endpoint(&self) -> Result<String, PortError> {
+        let port = self.tcp_port.host_port().await?;
+        let host_ip = self.runner.container_host_ip().await?;
+        let endpoint = format!("{host_ip}:{port}");
+
+        Ok(endpoint)
+    }
+}
+
+impl ToRunnableContainer for CoreDns {
+    fn to_runnable(&self, builder: RunnableContainerBuilder) -> RunnableContainer {
+        let (command, volumes) = if let Some(dir) = &self.corefile {
+            let corefile = format!("/{COREFILE}");
+            let mut volume = Volume::bind_mount(dir.as_ref().join(COREFILE), &corefile);
+            volume.read_only();
+            (vec![String::from("-conf"), corefile], vec![volume])
+        } else {
+            (vec![], vec![])
+        };
+
+        builder
+            .with_image(self.image.clone())
+            .with_command(command)
+            .with_volumes(volumes)
+            .with_wait_strategy(WaitStrategy::output_contains("CoreDNS-"))
+            .with_port_mappings([self.udp_port.clone(), self.tcp_port.clone()])
+            .build()
+    }
+}
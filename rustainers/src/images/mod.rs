@@ -3,12 +3,9 @@
 mod postgres;
 use indexmap::IndexMap;
 
-use crate::Container;
 use crate::ContainerStatus;
 use crate::ExposedPort;
 use crate::ImageReference;
-use crate::Port;
-use crate::PortError;
 use crate::RunnableContainer;
 use crate::RunnableContainerBuilder;
 use crate::ToRunnableContainer;
@@ -34,6 +31,9 @@ pub use self::mosquitto::*;
 mod nats;
 pub use self::nats::*;
 
+mod coredns;
+pub use self::coredns::*;
+
 /// A Generic Image
 ///
 /// ```rust, no_run
@@ -67,8 +67,10 @@ impl GenericImage {
             container_name: None,
             command: vec![],
             env: IndexMap::default(),
-            wait_strategy: WaitStrategy::State(ContainerStatus::Running),
+            wait_strategies: vec![WaitStrategy::State(ContainerStatus::Running)],
             port_mappings: vec![],
+            volumes: vec![],
+            build: None,
         };
         Self(result)
     }
@@ -92,9 +94,14 @@ impl GenericImage {
         self.0.env.insert(name.into(), value.into());
     }
 
-    /// Set the wait strategy
+    /// Set the wait strategy, it replaces the previous strategies
     pub fn set_wait_strategy(&mut self, wait_strategy: impl Into<WaitStrategy>) {
-        self.0.wait_strategy = wait_strategy.into();
+        self.0.wait_strategies = vec![wait_strategy.into()];
+    }
+
+    /// Add a wait strategy, all the strategies should be satisfied
+    pub fn add_wait_strategy(&mut self, wait_strategy: impl Into<WaitStrategy>) {
+        self.0.wait_strategies.push(wait_strategy.into());
     }
 
     /// Add a port to publish
@@ -111,28 +118,10 @@ impl ToRunnableContainer for GenericImage {
             container_name: self.0.container_name.clone(),
             command: self.0.command.clone(),
             env: self.0.env.clone(),
-            wait_strategy: self.0.wait_strategy.clone(),
+            wait_strategies: self.0.wait_strategies.clone(),
             port_mappings: self.0.port_mappings.clone(),
+            volumes: self.0.volumes.clone(),
+            build: self.0.build.clone(),
         }
     }
 }
-
-impl Container<GenericImage> {
-    /// Find the host port for a container port
-    ///
-    /// # Errors
-    ///
-    /// Fail if there is no mapping with the container port
-    /// Could fail if the port is not bind
-    pub async fn host_port(&self, container_port: impl Into<Port>) -> Result<Port, PortError> {
-        let container_port = container_port.into();
-
-        for mapping in &self.0.port_mappings {
-            if mapping.container_port == container_port {
-                return mapping.host_port().await;
-            }
-        }
-
-        Err(PortError::ContainerPortNotFound(container_port))
-    }
-}
@@ -135,6 +135,7 @@ impl ToRunnableContainer for Nats {
             .with_wait_strategy(WaitStrategy::stderr_contains(
                 "Listening for client connections",
             ))
+            .with_wait_strategy(WaitStrategy::scan_port(MONITORING_PORT))
             .with_port_mappings([
                 self.client_port.clone(),
                 self.cluster_port.clone(),
@@ -1,14 +1,34 @@
+use std::net::IpAddr;
 use std::time::Duration;
 
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+
 use crate::{
     Container, ExposedPort, HealthCheck, ImageName, Port, PortError, RunnableContainer,
-    RunnableContainerBuilder, ToRunnableContainer,
+    RunnableContainerBuilder, ToRunnableContainer, Volume, VolumeError,
 };
 
 const REDIS_IMAGE: &ImageName = &ImageName::new("docker.io/redis");
 
 const PORT: Port = Port(6379);
 
+/// The password environment variable, also read by `redis-cli`
+const PASSWORD_ENV: &str = "REDISCLI_AUTH";
+
+const TLS_CERT_PATH: &str = "/tls/redis.crt";
+
+const TLS_KEY_PATH: &str = "/tls/redis.key";
+
+/// The self-signed certificate used by [`Redis::with_tls`]
+///
+/// The certificate is only valid for `localhost` and `127.0.0.1`,
+/// it should be trusted by the client to connect to the container.
+///
+/// ⚠️ WARNING the private key is public, only use it for tests
+pub const REDIS_TLS_CERTIFICATE: &str = include_str!("./assets/redis-tls.crt");
+
+const REDIS_TLS_KEY: &str = include_str!("./assets/redis-tls.key");
+
 /// A `Redis` image
 ///
 /// # Example
@@ -20,7 +40,9 @@ const PORT: Port = Port(6379);
 /// let default_image = Redis::default();
 ///
 /// let custom_image = Redis::default()
-///        .with_tag("7.2");
+///        .with_tag("7.2")
+///        .with_password("passwd")
+///        .with_tls()?;
 ///
 /// # let runner = rustainers::runner::Runner::auto()?;
 /// // ...
@@ -33,6 +55,14 @@ const PORT: Port = Port(6379);
 pub struct Redis {
     image: ImageName,
     port: ExposedPort,
+    password: Option<String>,
+    tls: Option<RedisTls>,
+}
+
+#[derive(Debug, Clone)]
+struct RedisTls {
+    cert: Volume,
+    key: Volume,
 }
 
 impl Redis {
@@ -58,45 +88,243 @@ impl Redis {
         self.port = port;
         self
     }
-}
 
-impl Redis {}
+    /// Set the password
+    ///
+    /// It's passed with the `REDISCLI_AUTH` environment variable, not in the command arguments.
+    #[must_use]
+    pub fn with_password(self, password: impl Into<String>) -> Self {
+        let password = Some(password.into());
+        Self { password, ..self }
+    }
+
+    /// Enable TLS
+    ///
+    /// The server only accepts TLS connections, with the [`REDIS_TLS_CERTIFICATE`] certificate.
+    /// The clients are not authenticated with certificates.
+    ///
+    /// # Errors
+    ///
+    /// Fail if we cannot write the certificate files
+    pub fn with_tls(self) -> Result<Self, VolumeError> {
+        let cert = Volume::bind_content(REDIS_TLS_CERTIFICATE, TLS_CERT_PATH)?;
+        let key = Volume::bind_content(REDIS_TLS_KEY, TLS_KEY_PATH)?;
+        let tls = Some(RedisTls { cert, key });
+        Ok(Self { tls, ..self })
+    }
+}
 
 impl Default for Redis {
     fn default() -> Self {
         Self {
             image: REDIS_IMAGE.clone(),
             port: ExposedPort::new(PORT),
+            password: None,
+            tls: None,
+        }
+    }
+}
+
+/// The `Redis` connection information
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RedisConnInfo {
+    /// The host
+    pub host: IpAddr,
+    /// The port
+    pub port: Port,
+    /// The password
+    pub password: Option<String>,
+    /// Is TLS enabled
+    pub tls: bool,
+}
+
+impl RedisConnInfo {
+    /// The connection URL, i.e. `redis://{host}:{port}`
+    ///
+    /// The scheme is `rediss` with TLS, and the password is included (percent-encoded) if set,
+    /// e.g. `rediss://:{password}@{host}:{port}`
+    #[must_use]
+    pub fn url(&self) -> String {
+        let Self {
+            host,
+            port,
+            password,
+            tls,
+        } = self;
+        let scheme = if *tls { "rediss" } else { "redis" };
+        if let Some(password) = password {
+            let password = utf8_percent_encode(password, NON_ALPHANUMERIC);
+            format!("{scheme}://:{password}@{host}:{port}")
+        } else {
+            format!("{scheme}://{host}:{port}")
         }
     }
 }
 
 impl Container<Redis> {
+    /// Get the connection information
+    ///
+    /// # Errors
+    ///
+    /// Could fail if the port is not bind
+    pub async fn conn_info(&self) -> Result<RedisConnInfo, PortError> {
+        let port = self.port.host_port().await?;
+        let host = self.runner.container_host_ip().await?;
+        Ok(RedisConnInfo {
+            host,
+            port,
+            password: self.password.clone(),
+            tls: self.tls.is_some(),
+        })
+    }
+
     /// Get endpoint URL
     ///
+    /// See [`RedisConnInfo::url`]
+    ///
     /// # Errors
     ///
     /// Could fail if the port is not bind
     pub async fn endpoint(&self) -> Result<String, PortError> {
-        let port = self.port.host_port().await?;
-        let host_ip = self.runner.container_host_ip().await?;
-        let url = format!("redis://{host_ip}:{port}");
+        let conn_info = self.conn_info().await?;
+        Ok(conn_info.url())
+    }
 
-        Ok(url)
+    /// Connect a multiplexed client
+    ///
+    /// Note that TLS requires a `redis` TLS feature, e.g. `tls-rustls`, `tokio-rustls-comp`.
+    ///
+    /// # Errors
+    ///
+    /// Could fail if the port is not bind, or if the connection fails
+    #[cfg(feature = "redis-client")]
+    pub async fn connect(&self) -> Result<redis::aio::MultiplexedConnection, RedisClientError> {
+        let endpoint = self.endpoint().await?;
+        let client = redis::Client::open(endpoint)?;
+        let connection = client.get_multiplexed_async_connection().await?;
+        Ok(connection)
     }
 }
+
+/// The `Redis` client error, see [`Container::<Redis>::connect`](Container::connect)
+#[cfg(feature = "redis-client")]
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum RedisClientError {
+    /// Port error
+    #[error(transparent)]
+    PortError(#[from] PortError),
+
+    /// Client error
+    #[error(transparent)]
+    ClientError(#[from] redis::RedisError),
+}
+
 impl ToRunnableContainer for Redis {
     fn to_runnable(&self, builder: RunnableContainerBuilder) -> RunnableContainer {
+        let tls_port = PORT.to_string();
+        let mut command = vec![];
+        let mut cli = vec!["redis-cli"];
+        if self.tls.is_some() {
+            command.extend([
+                "--port",
+                "0",
+                "--tls-port",
+                &tls_port,
+                "--tls-cert-file",
+                TLS_CERT_PATH,
+                "--tls-key-file",
+                TLS_KEY_PATH,
+                "--tls-ca-cert-file",
+                TLS_CERT_PATH,
+                "--tls-auth-clients",
+                "no",
+            ]);
+            cli.extend(["--tls", "--cacert", TLS_CERT_PATH]);
+        }
+        cli.extend(["--raw", "incr", "ping"]);
+        // The password is read from the environment, to keep it out of the command arguments
+        let server = format!(r#"exec redis-server --requirepass "${PASSWORD_ENV}" "$@""#);
+        if self.password.is_some() {
+            let shell = ["sh", "-c", &server, "redis-server"];
+            command.splice(0..0, shell);
+        } else if !command.is_empty() {
+            command.insert(0, "redis-server");
+        }
+        let env = self
+            .password
+            .iter()
+            .map(|password| (PASSWORD_ENV, password.clone()));
+        let volumes = self
+            .tls
+            .iter()
+            .flat_map(|tls| [tls.cert.clone(), tls.key.clone()]);
+
         builder
             .with_image(self.image.clone())
+            .with_command(command)
+            .with_env(env)
             .with_wait_strategy(
                 HealthCheck::builder()
-                    .with_command("redis-cli --raw incr ping")
+                    .with_command_exec(cli)
                     .with_start_period(Duration::from_millis(96))
                     .with_interval(Duration::from_millis(96))
                     .build(),
             )
+            .with_volumes(volumes)
             .with_port_mappings([self.port.clone()])
             .build()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use assert2::check;
+
+    use super::*;
+
+    #[test]
+    fn should_build_url() {
+        let conn_info = RedisConnInfo {
+            host: IpAddr::from([127, 0, 0, 1]),
+            port: Port(6379),
+            password: None,
+            tls: false,
+        };
+        check!(conn_info.url() == "redis://127.0.0.1:6379");
+
+        let conn_info = RedisConnInfo {
+            password: Some(String::from("passwd")),
+            tls: true,
+            ..conn_info
+        };
+        check!(conn_info.url() == "rediss://:passwd@127.0.0.1:6379");
+
+        let conn_info = RedisConnInfo {
+            password: Some(String::from("p@ss:w/rd")),
+            tls: false,
+            ..conn_info
+        };
+        check!(conn_info.url() == "redis://:p%40ss%3Aw%2Frd@127.0.0.1:6379");
+    }
+
+    #[test]
+    fn should_not_pass_password_as_argument() {
+        let image = Redis::default()
+            .with_password("s3cr3t")
+            .with_tls()
+            .expect("tls");
+        let runnable = image.to_runnable(RunnableContainer::builder());
+        check!(!runnable.command.iter().any(|arg| arg.contains("s3cr3t")));
+        check!(runnable.env.get(PASSWORD_ENV) == Some(&String::from("s3cr3t")));
+        check!(runnable.command.contains(&String::from("--tls-port")));
+    }
+
+    #[test]
+    fn should_configure_tls() {
+        let image = Redis::default().with_tls().expect("tls");
+        let runnable = image.to_runnable(RunnableContainer::builder());
+        check!(runnable.command.contains(&String::from("--tls-port")));
+        check!(runnable.volumes.len() == 2);
+    }
+}
@@ -4,10 +4,14 @@ use tokio::io::{AsyncBufReadExt, AsyncRead, BufReader};
 use tokio::sync::mpsc;
 use tracing::info;
 
+/// The default capacity of the channel used to read lines
+pub(crate) const DEFAULT_LOG_BUFFER_SIZE: usize = 256;
+
 #[derive(Debug, Clone, Copy)]
 pub enum StdIoKind {
     Out,
     Err,
+    Both,
 }
 
 impl Display for StdIoKind {
@@ -15,12 +19,14 @@ impl Display for StdIoKind {
         match self {
             Self::Out => write!(f, "stdout"),
             Self::Err => write!(f, "stderr"),
+            Self::Both => write!(f, "stdout and stderr"),
         }
     }
 }
 
 /// An error during reading lines
 #[derive(Debug, thiserror::Error)]
+#[allow(clippy::enum_variant_names)]
 pub enum ReadLinesError {
     /// Fail to read the stream
     #[error(transparent)]
@@ -29,6 +35,10 @@ pub enum ReadLinesError {
     /// Fail to send the line
     #[error(transparent)]
     SenderError(#[from] mpsc::error::SendError<String>),
+
+    /// Fail to join the reading task
+    #[error(transparent)]
+    JoinError(#[from] tokio::task::JoinError),
 }
 
 pub async fn read_lines<R>(
@@ -43,6 +53,7 @@ where
         return Ok(());
     };
 
+    // Note that the last line is also sent even without a trailing newline
     let buf_reader = BufReader::new(reader);
     let mut lines = buf_reader.lines();
     while let Some(line) = lines.next_line().await? {
@@ -51,3 +62,35 @@ where
 
     Ok(())
 }
+
+#[cfg(test)]
+#[allow(clippy::ignored_unit_patterns)]
+mod tests {
+    use assert2::{check, let_assert};
+
+    use super::*;
+
+    async fn collect_lines(input: &'static [u8], buffer_size: usize) -> Vec<String> {
+        let (tx, mut rx) = mpsc::channel(buffer_size);
+        let task = tokio::spawn(read_lines(Some(input), tx));
+
+        let mut result = vec![];
+        while let Some(line) = rx.recv().await {
+            result.push(line);
+        }
+        let_assert!(Ok(Ok(())) = task.await);
+        result
+    }
+
+    #[tokio::test]
+    async fn should_read_lines() {
+        let lines = collect_lines(b"first\nsecond\n", DEFAULT_LOG_BUFFER_SIZE).await;
+        check!(lines == ["first", "second"]);
+    }
+
+    #[tokio::test]
+    async fn should_read_last_line_without_trailing_newline() {
+        let lines = collect_lines(b"first\r\nsecond\nlast", 1).await;
+        check!(lines == ["first", "second", "last"]);
+    }
+}
@@ -23,6 +23,8 @@ pub(crate) mod version;
 
 pub(crate) mod io;
 
+pub(crate) mod timestamp;
+
 /// Runners like docker, podman, ...
 pub mod runner;
 
@@ -6,7 +6,7 @@ use crate::Port;
 #[non_exhaustive]
 pub enum PortError {
     /// Invalid port mapping
-    #[error("Invalid port mapping, expect a `<host port>:<container port>`, got {0}")]
+    #[error("Invalid port mapping, expect a `<host port>:<container port>[/<protocol>]`, got {0}")]
     InvalidPortMapping(String),
 
     /// The port is not yet bind
@@ -1,3 +1,5 @@
+use std::fmt::Display;
+use std::hash::{Hash, Hasher};
 use std::str::FromStr;
 use std::sync::Arc;
 
@@ -31,10 +33,80 @@ use super::{Port, PortError};
 /// # use rustainers::ExposedPort;
 /// let port_mapping = ExposedPort::fixed(80, 8080);
 /// ```
+///
+/// To use the same port on the host, e.g. to have a predictable URL,
+/// create the exposed host port `8080` targeting the container `8080` port:
+///
+/// ```rust
+/// # use rustainers::ExposedPort;
+/// let port_mapping = ExposedPort::same(8080);
+/// assert_eq!(port_mapping.container_port(), 8080);
+/// ```
+///
+/// By default the port use the TCP protocol, you can expose an UDP port.
+/// For example, to create an exposed port targeting the container `53/udp` port:
+///
+/// ```rust
+/// # use rustainers::{ExposedPort, PortProtocol};
+/// let port_mapping = ExposedPort::udp(53);
+/// assert_eq!(port_mapping.protocol(), PortProtocol::Udp);
+/// ```
+///
+/// An exposed port is already shared: the clones share the same host port,
+/// so an image can keep a clone, and read the bound port with [`ExposedPort::host_port`]
+/// once the container is started, without any additional lock.
+///
+/// The equality and the hash only use the container port and the protocol,
+/// the host port is ignored because it's only known after the container start.
+/// So it's safe to use an exposed port as a map key,
+/// even if clippy could warn about the interior mutability (`clippy::mutable_key_type`).
+///
+/// ```rust
+/// # use rustainers::ExposedPort;
+/// assert_eq!(ExposedPort::new(80), ExposedPort::fixed(80, 8080));
+/// assert_ne!(ExposedPort::new(53), ExposedPort::udp(53));
+/// ```
 #[derive(Debug, Clone)]
 pub struct ExposedPort {
     pub(crate) container_port: Port,
     pub(crate) host_port: Arc<Mutex<Option<Port>>>,
+    pub(crate) protocol: PortProtocol,
+}
+
+/// A port protocol
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum PortProtocol {
+    /// TCP
+    #[default]
+    Tcp,
+
+    /// UDP
+    Udp,
+}
+
+impl Display for PortProtocol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Tcp => write!(f, "tcp"),
+            Self::Udp => write!(f, "udp"),
+        }
+    }
+}
+
+impl PartialEq for ExposedPort {
+    fn eq(&self, other: &Self) -> bool {
+        self.container_port == other.container_port && self.protocol == other.protocol
+    }
+}
+
+impl Eq for ExposedPort {}
+
+impl Hash for ExposedPort {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.container_port.hash(state);
+        self.protocol.hash(state);
+    }
 }
 
 impl ExposedPort {
@@ -43,6 +115,15 @@ impl ExposedPort {
         Self {
             container_port: container_port.into(),
             host_port: Arc::default(),
+            protocol: PortProtocol::Tcp,
+        }
+    }
+
+    /// Create an UDP exposed port
+    pub fn udp(container_port: impl Into<Port>) -> ExposedPort {
+        Self {
+            protocol: PortProtocol::Udp,
+            ..Self::new(container_port)
         }
     }
 
@@ -51,9 +132,40 @@ impl ExposedPort {
         Self {
             container_port: container_port.into(),
             host_port: Arc::new(Mutex::new(Some(host_port.into()))),
+            protocol: PortProtocol::Tcp,
         }
     }
 
+    /// Create an exposed port with a fixed host port, from raw port numbers
+    ///
+    /// It's the same as [`ExposedPort::fixed`].
+    ///
+    /// ```rust
+    /// # use rustainers::ExposedPort;
+    /// let redis_port: u16 = 6379;
+    /// let port_mapping = ExposedPort::fixed_u16(redis_port, 9125);
+    /// assert_eq!(port_mapping.container_port(), redis_port);
+    /// ```
+    #[must_use]
+    pub fn fixed_u16(container_port: u16, host_port: u16) -> ExposedPort {
+        Self::fixed(container_port, host_port)
+    }
+
+    /// Create an exposed port bound to the same host port, e.g. to have a predictable URL
+    ///
+    /// Note that the host port should be available, so concurrent containers cannot use the same port.
+    #[must_use]
+    pub fn same(port: impl Into<Port>) -> ExposedPort {
+        let port = port.into();
+        Self::fixed(port, port)
+    }
+
+    /// Set the protocol
+    #[must_use]
+    pub fn with_protocol(self, protocol: PortProtocol) -> Self {
+        Self { protocol, ..self }
+    }
+
     /// Get the bound port (host)
     ///
     /// # Errors
@@ -70,11 +182,21 @@ impl ExposedPort {
         self.container_port
     }
 
+    /// Get the protocol
+    #[must_use]
+    pub fn protocol(&self) -> PortProtocol {
+        self.protocol
+    }
+
     pub(crate) async fn to_publish(&self) -> String {
         let port = self.host_port.lock().await;
-        port.map_or(self.container_port.to_string(), |host| {
+        let publish = port.map_or(self.container_port.to_string(), |host| {
             format!("{host}:{}", self.container_port)
-        })
+        });
+        match self.protocol {
+            PortProtocol::Tcp => publish,
+            PortProtocol::Udp => format!("{publish}/udp"),
+        }
     }
 
     /// Bind the host port (if it's not already bound)
@@ -94,6 +216,12 @@ impl FromStr for ExposedPort {
         let Some((host, container)) = str.split_once(':') else {
             return Err(PortError::InvalidPortMapping(str.to_string()));
         };
+        let (container, protocol) = match container.split_once('/') {
+            Some((container, "tcp")) => (container, PortProtocol::Tcp),
+            Some((container, "udp")) => (container, PortProtocol::Udp),
+            Some(_) => return Err(PortError::InvalidPortMapping(str.to_string())),
+            None => (container, PortProtocol::Tcp),
+        };
         let host_port = host
             .parse()
             .map_err(|_| PortError::InvalidPortMapping(str.to_string()))?;
@@ -104,6 +232,7 @@ impl FromStr for ExposedPort {
         Ok(Self {
             host_port: Arc::new(Mutex::new(Some(host_port))),
             container_port,
+            protocol,
         })
     }
 }
@@ -123,6 +252,32 @@ mod tests {
         check!(result.host_port().await.expect("host port") == 1234);
     }
 
+    #[tokio::test]
+    async fn should_parse_udp_exposed_port() {
+        let str = "1234:53/udp";
+        let result = str.parse::<ExposedPort>().expect("port");
+        check!(result.container_port() == 53);
+        check!(result.protocol() == PortProtocol::Udp);
+        check!(result.to_publish().await == str);
+    }
+
+    #[tokio::test]
+    async fn should_create_same_port() {
+        let result = ExposedPort::same(8080);
+        check!(result.container_port() == 8080);
+        check!(result.host_port().await.expect("host port") == 8080);
+        check!(result.protocol() == PortProtocol::Tcp);
+        check!(result.to_publish().await == "8080:8080");
+    }
+
+    #[tokio::test]
+    async fn should_create_fixed_u16_port() {
+        let result = ExposedPort::fixed_u16(6379, 9125);
+        check!(result.container_port() == 6379);
+        check!(result.host_port().await.expect("host port") == 9125);
+        check!(result.to_publish().await == "9125:6379");
+    }
+
     #[rstest::rstest]
     #[case::empty("")]
     #[case::only_one("1234")]
@@ -130,12 +285,28 @@ mod tests {
     #[case::empty_port("1234:")]
     #[case::invalid_first_port("a:80")]
     #[case::invalid_second_port("1234:a")]
+    #[case::invalid_protocol("1234:80/sctp")]
     fn should_not_parse_invalid_exposed_port(#[case] str: &str) {
         let result = str.parse::<ExposedPort>();
         let_assert!(Err(PortError::InvalidPortMapping(s2)) = result);
         check!(str == s2);
     }
 
+    #[test]
+    #[allow(clippy::mutable_key_type)] // the host port is not hashed
+    fn should_use_container_port_and_protocol_as_identity() {
+        let ports = [
+            ExposedPort::new(80),
+            ExposedPort::fixed(80, 8080),
+            ExposedPort::udp(80),
+        ]
+        .into_iter()
+        .collect::<std::collections::HashSet<_>>();
+        check!(ports.len() == 2);
+        check!(ports.contains(&ExposedPort::new(80)));
+        check!(ports.contains(&ExposedPort::udp(80)));
+    }
+
     #[tokio::test]
     async fn should_bind_port() {
         const CONTAINER: u16 = 42;
@@ -0,0 +1,45 @@
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+use super::{ContainerError, Runner, RunnerError};
+
+/// The output of an image build, see [`Runner::build_stream`]
+///
+/// The build is stopped when the stream is dropped.
+#[derive(Debug)]
+pub struct BuildStream {
+    pub(crate) runner: Runner,
+    pub(crate) image: String,
+    pub(crate) output: mpsc::Receiver<String>,
+    pub(crate) build: JoinHandle<Result<(), ContainerError>>,
+}
+
+impl BuildStream {
+    /// Receive the next line of the build output
+    ///
+    /// The lines of both the standard output and the standard error are sent,
+    /// it returns `None` when the build is done.
+    pub async fn next_line(&mut self) -> Option<String> {
+        self.output.recv().await
+    }
+
+    /// Wait until the build is done, and return its result
+    ///
+    /// The remaining lines of the build output are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Fail if the build fails
+    pub async fn result(mut self) -> Result<(), RunnerError> {
+        while self.output.recv().await.is_some() {}
+        let result = match self.build.await {
+            Ok(result) => result,
+            Err(err) => Err(ContainerError::from(err)),
+        };
+        result.map_err(|source| RunnerError::BuildError {
+            runner: self.runner,
+            image: self.image,
+            source: Box::new(source),
+        })
+    }
+}
@@ -0,0 +1,50 @@
+use std::net::IpAddr;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// The runner settings
+///
+/// It's shared by the runner clones behind an [`Arc`](std::sync::Arc),
+/// the `Runner::with_*` functions create a new configuration.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct RunnerConfig {
+    /// The default stop timeout (in seconds)
+    pub(crate) stop_timeout: Option<u16>,
+
+    /// The host address override
+    pub(crate) host_address: Option<IpAddr>,
+
+    /// The docker configuration directory
+    pub(crate) config_dir: Option<PathBuf>,
+
+    /// The compose binary override, e.g. `docker-compose`, instead of the built-in compose command
+    pub(crate) compose_binary: Option<String>,
+
+    /// The containerd namespace (nerdctl only), if `None` the nerdctl default is used (i.e. `default`)
+    pub(crate) namespace: Option<String>,
+
+    /// The runner instance
+    pub(crate) instance: RunnerInstance,
+}
+
+/// The runner instance, see [`Runner::stop_all`](super::Runner::stop_all)
+///
+/// It's created with the runner, and shared by its clones.
+/// It's ignored by the equality, so runners with the same settings are still equal.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RunnerInstance(Arc<()>);
+
+impl RunnerInstance {
+    /// Check if both runners come from the same instance
+    pub(crate) fn is_same(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl PartialEq for RunnerInstance {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for RunnerInstance {}
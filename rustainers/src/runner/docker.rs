@@ -1,4 +1,6 @@
 use std::fmt::Display;
+use std::net::IpAddr;
+use std::sync::Arc;
 
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
@@ -6,9 +8,10 @@ use tracing::{debug, info};
 
 use crate::cmd::Cmd;
 use crate::runner::RunnerError;
+use crate::runner::DOCKER_CONFIG_ENV;
 use crate::version::Version;
 
-use super::InnerRunner;
+use super::{probe_version, InnerRunner, RunnerConfig};
 
 const MINIMAL_VERSION: Version = Version::new(1, 20);
 const COMPOSE_MINIMAL_VERSION: Version = Version::new(2, 6);
@@ -20,18 +23,39 @@ const COMPOSE_MINIMAL_VERSION: Version = Version::new(2, 6);
 /// It requires docker client v1.20+
 ///
 /// Docker compose should be v2.10+
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Docker {
     /// The docker client version
     pub version: Version,
     /// The docker compose client version
     pub compose_version: Option<Version>,
+    /// Whether the docker daemon runs in rootless mode
+    pub rootless: bool,
+
+    /// The runner settings
+    pub(crate) config: Arc<RunnerConfig>,
 }
 
 #[async_trait]
 impl InnerRunner for Docker {
+    fn stop_timeout(&self) -> Option<u16> {
+        self.config.stop_timeout
+    }
+
+    fn host_address(&self) -> Option<IpAddr> {
+        self.config.host_address
+    }
+
     fn command(&self) -> Cmd<'static> {
-        Cmd::new("docker")
+        let mut cmd = Cmd::new("docker");
+        if let Some(config_dir) = &self.config.config_dir {
+            cmd.push_safe_env(DOCKER_CONFIG_ENV, config_dir.display().to_string());
+        }
+        cmd
+    }
+
+    fn is_rootless(&self) -> bool {
+        self.rootless
     }
 }
 
@@ -39,7 +63,8 @@ impl Display for Docker {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "Docker {}", self.version)?;
         if let Some(compose_version) = self.compose_version {
-            write!(f, " - compose {compose_version}")?;
+            let compose = self.config.compose_binary.as_deref().unwrap_or("compose");
+            write!(f, " - {compose} {compose_version}")?;
         }
         Ok(())
     }
@@ -49,12 +74,14 @@ pub(super) fn create() -> Result<Docker, RunnerError> {
     // Check binary version
     let mut cmd = Cmd::new("docker");
     cmd.push_args(["version", "--format", "{{json .}}"]);
-    let Ok(Some(version)) = cmd.json_blocking::<Option<DockerVersion>>() else {
+    let Ok(Some(version)) = probe_version::<Option<DockerVersion>>(&cmd) else {
         return Err(RunnerError::CommandNotAvailable(String::from("docker")));
     };
 
-    // Check client version
-    let current = version.client.api_version;
+    // Check client version, fallback to the server version
+    let Some(current) = version.api_version() else {
+        return Err(RunnerError::CommandNotAvailable(String::from("docker")));
+    };
     debug!("Found docker version: {current}");
     if current < MINIMAL_VERSION {
         return Err(RunnerError::UnsupportedVersion {
@@ -65,10 +92,13 @@ pub(super) fn create() -> Result<Docker, RunnerError> {
     }
 
     let compose_version = compose_version();
+    let rootless = rootless();
 
     Ok(Docker {
         version: current,
         compose_version,
+        rootless,
+        config: Arc::default(),
     })
 }
 
@@ -93,18 +123,73 @@ fn compose_version() -> Option<Version> {
     Some(version)
 }
 
+/// Check the version of a compose binary, e.g. `docker-compose`
+pub(super) fn compose_binary_version(binary: &str) -> Result<Version, RunnerError> {
+    let mut cmd = Cmd::new(binary.to_string());
+    cmd.push_args(["version", "--format", "json"]);
+    let Ok(Some(docker_compose_version)) = cmd.json_blocking::<Option<DockerComposeVersion>>()
+    else {
+        return Err(RunnerError::CommandNotAvailable(binary.to_string()));
+    };
+
+    let current = docker_compose_version.version;
+    debug!("Compose ({binary}) version: {current}");
+    if current < COMPOSE_MINIMAL_VERSION {
+        return Err(RunnerError::UnsupportedVersion {
+            command: binary.to_string(),
+            current,
+            minimal: COMPOSE_MINIMAL_VERSION,
+        });
+    }
+    Ok(current)
+}
+
+fn rootless() -> bool {
+    let mut cmd = Cmd::new("docker");
+    cmd.push_args(["info", "--format", "{{json .SecurityOptions}}"]);
+    let Ok(Some(security_options)) = cmd.json_blocking::<Option<Vec<String>>>() else {
+        debug!("Fail to check docker security options");
+        return false;
+    };
+
+    let result = is_rootless(&security_options);
+    debug!("Docker rootless mode: {result}");
+    result
+}
+
+fn is_rootless(security_options: &[String]) -> bool {
+    // Security options look like `name=seccomp,profile=builtin` or `name=rootless`
+    security_options.iter().any(|option| {
+        option
+            .split(',')
+            .any(|part| part == "name=rootless" || part == "rootless")
+    })
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 struct DockerVersion {
-    client: DockerVersionItem,
+    #[serde(default)]
+    client: Option<DockerVersionItem>,
+    #[serde(default)]
     server: Option<DockerVersionItem>,
 }
 
+impl DockerVersion {
+    /// The client API version, or the server API version if the client does not provide it
+    fn api_version(&self) -> Option<Version> {
+        let client = self.client.as_ref().and_then(|it| it.api_version);
+        client.or_else(|| self.server.as_ref().and_then(|it| it.api_version))
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 struct DockerVersionItem {
-    api_version: Version,
-    version: Version,
+    #[serde(default)]
+    api_version: Option<Version>,
+    #[serde(default)]
+    version: Option<Version>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -115,6 +200,8 @@ struct DockerComposeVersion {
 #[cfg(test)]
 mod tests {
 
+    use std::path::PathBuf;
+
     use super::*;
 
     #[test]
@@ -131,6 +218,47 @@ mod tests {
         insta::assert_debug_snapshot!(version);
     }
 
+    #[rstest::rstest]
+    #[case::client(
+        r#"{"Client":{"ApiVersion":"1.43"},"Server":{"ApiVersion":"1.41"}}"#,
+        Some(Version::new(1, 43))
+    )]
+    #[case::thin_client(
+        r#"{"Client":{"Version":"1.0"},"Server":{"ApiVersion":"1.41"}}"#,
+        Some(Version::new(1, 41))
+    )]
+    #[case::no_client(r#"{"Server":{"ApiVersion":"1.41"}}"#, Some(Version::new(1, 41)))]
+    #[case::none(r#"{"Client":{},"Server":null}"#, None)]
+    fn should_fallback_to_server_version(#[case] json: &str, #[case] expected: Option<Version>) {
+        let version = serde_json::from_str::<DockerVersion>(json).expect("docker version");
+        assert2::check!(version.api_version() == expected);
+    }
+
+    #[rstest::rstest]
+    #[case::rootless(&["name=seccomp,profile=builtin", "name=rootless", "name=cgroupns"], true)]
+    #[case::rootful(&["name=apparmor", "name=seccomp,profile=builtin", "name=cgroupns"], false)]
+    #[case::empty(&[], false)]
+    fn should_detect_rootless(#[case] options: &[&str], #[case] expected: bool) {
+        let options = options.iter().map(ToString::to_string).collect::<Vec<_>>();
+        let result = is_rootless(&options);
+        assert2::check!(result == expected);
+    }
+
+    #[test]
+    fn should_set_docker_config() {
+        let docker = Docker {
+            version: Version::new(1, 43),
+            compose_version: None,
+            rootless: false,
+            config: Arc::new(RunnerConfig {
+                config_dir: Some(PathBuf::from("/ci/docker")),
+                ..RunnerConfig::default()
+            }),
+        };
+        let cmd = docker.command();
+        assert2::check!(cmd.to_string() == "DOCKER_CONFIG=/ci/docker docker");
+    }
+
     #[cfg(feature = "ensure-docker")]
     #[test]
     fn should_works() {
@@ -1,9 +1,14 @@
 use std::env::VarError;
+use std::fmt::{self, Display};
 use std::path::PathBuf;
+use std::time::Duration;
 
 use crate::cmd::CommandError;
 use crate::version::Version;
-use crate::{ContainerId, IdError, Network, Port, RunnableContainer, VolumeError, WaitStrategy};
+use crate::{
+    ContainerId, ContainerStatus, IdError, Network, Port, RunnableContainer, VolumeError,
+    WaitStrategy,
+};
 
 use super::Runner;
 
@@ -26,6 +31,10 @@ pub enum RunnerError {
         minimal: Version,
     },
 
+    /// The runner does not support a compose binary override
+    #[error("The compose binary cannot be overridden with {0}")]
+    ComposeBinaryUnsupported(Box<Runner>),
+
     /// Unable to find an available runner
     #[error("No runner available")]
     NoRunnerAvailable,
@@ -54,6 +63,94 @@ pub enum RunnerError {
         source: Box<ContainerError>,
     },
 
+    /// Fail to retrieve the container logs
+    #[error("Fail to retrieve logs of container {id} because {source}\nrunner: {runner}")]
+    LogsError {
+        /// The runner
+        runner: Runner,
+        /// The container id
+        id: ContainerId,
+        /// The source error
+        source: Box<ContainerError>,
+    },
+
+    /// Fail to watch the container stats
+    #[error("Fail to watch stats of container {id} because {source}\nrunner: {runner}")]
+    StatsError {
+        /// The runner
+        runner: Runner,
+        /// The container id
+        id: ContainerId,
+        /// The source error
+        source: Box<ContainerError>,
+    },
+
+    /// Fail to build an image
+    #[error("Fail to build image {image} because {source}\nrunner: {runner}")]
+    BuildError {
+        /// The runner
+        runner: Runner,
+        /// The image
+        image: String,
+        /// The source error
+        source: Box<ContainerError>,
+    },
+
+    /// Fail to inspect an image
+    #[error("Fail to inspect image {image} because {source}\nrunner: {runner}")]
+    InspectImageError {
+        /// The runner
+        runner: Runner,
+        /// The image
+        image: String,
+        /// The source error
+        source: Box<ContainerError>,
+    },
+
+    /// Fail to attach an existing container
+    #[error("Fail to attach container '{name}' because {source}\nrunner: {runner}")]
+    AttachError {
+        /// The runner
+        runner: Runner,
+        /// The container name or id
+        name: String,
+        /// The source error
+        source: Box<ContainerError>,
+    },
+
+    /// Fail to read a file in a container
+    #[error("Fail to read a file in container {id} because {source}\nrunner: {runner}")]
+    ReadFileError {
+        /// The runner
+        runner: Runner,
+        /// The container id
+        id: ContainerId,
+        /// The source error
+        source: Box<ContainerError>,
+    },
+
+    /// Fail to write a file in a container
+    #[error("Fail to write a file in container {id} because {source}\nrunner: {runner}")]
+    WriteFileError {
+        /// The runner
+        runner: Runner,
+        /// The container id
+        id: ContainerId,
+        /// The source error
+        source: Box<ContainerError>,
+    },
+
+    /// Fail to inspect a container
+    #[error("Fail to inspect container {id} because {source}\nrunner: {runner}")]
+    InspectError {
+        /// The runner
+        runner: Runner,
+        /// The container id
+        id: ContainerId,
+        /// The source error
+        source: Box<ContainerError>,
+    },
+
     /// Fail to create a network
     #[error("Fail to create network '{name}' because {source}\nrunner: {runner}")]
     CreateNetworkError {
@@ -65,6 +162,17 @@ pub enum RunnerError {
         source: Box<ContainerError>,
     },
 
+    /// Fail to create a pod
+    #[error("Fail to create pod '{name}' because {source}\nrunner: {runner}")]
+    CreatePodError {
+        /// The runner
+        runner: Runner,
+        /// The pod name
+        name: String,
+        /// The source error
+        source: Box<ContainerError>,
+    },
+
     /// Fail to create a volume
     #[error("Fail to create volume'{name}' because {source}\nrunner: {runner}")]
     CreateVolumeError {
@@ -98,6 +206,36 @@ pub enum RunnerError {
         source: Box<ContainerError>,
     },
 
+    /// Fail to list containers
+    #[error("Fail to list containers because {source}\nrunner: {runner}")]
+    ListContainersError {
+        /// The runner
+        runner: Runner,
+
+        /// The source error
+        source: Box<ContainerError>,
+    },
+
+    /// Fail to list networks
+    #[error("Fail to list networks because {source}\nrunner: {runner}")]
+    ListNetworksError {
+        /// The runner
+        runner: Runner,
+
+        /// The source error
+        source: Box<ContainerError>,
+    },
+
+    /// Fail to list volumes
+    #[error("Fail to list volumes because {source}\nrunner: {runner}")]
+    ListVolumesError {
+        /// The runner
+        runner: Runner,
+
+        /// The source error
+        source: Box<ContainerError>,
+    },
+
     /// Fail to retrieve network IP
     #[error("Fail to retrieve network named '{network}' IP for container {container} because {source}\nrunner: {runner}")]
     FindNetworkIpError {
@@ -144,6 +282,17 @@ pub enum RunnerError {
         source: Box<ContainerError>,
     },
 
+    /// Fail to wait until a container is removed
+    #[error("Fail to wait the removal of container {id} because {source}\nrunner: {runner}")]
+    WaitRemovedError {
+        /// The runner
+        runner: Runner,
+        /// The container id
+        id: ContainerId,
+        /// The source error
+        source: Box<ContainerError>,
+    },
+
     /// Fail to retrieve host ip address
     #[error("Can not fetch host because {source}\nrunner: {runner}")]
     HostIpError {
@@ -164,6 +313,13 @@ pub enum RunnerError {
         source: Box<crate::compose::ComposeError>,
     },
 
+    /// Compose is not available for the runner
+    #[error("Compose is not available\nrunner: {runner}")]
+    ComposeUnavailable {
+        /// The runner
+        runner: Runner,
+    },
+
     /// Different runner
     #[error("The operation need to be done with the same runner\ncurrent: {runner}\ncontainer runner: {container_runner}")]
     DifferentRunner {
@@ -191,13 +347,37 @@ pub enum ContainerError {
     #[error("Container '{0}' cannot be started")]
     ContainerCannotBeStarted(ContainerId),
 
+    /// The container is not started in time
+    #[error("Container is not started after {0:?}")]
+    StartTimeout(Duration),
+
     /// Fail to resume a container
     #[error("Container '{0}' cannot be resumed (unpause)")]
     ContainerCannotBeResumed(ContainerId),
 
     /// Invalid container state
-    #[error("Container {0} state {1:?} is unexpected")]
-    InvalidContainerState(ContainerId, String),
+    #[error(
+        "Container {id} state {state:?} is unexpected, exit code: {exit_code}\nLast logs:\n{logs}"
+    )]
+    InvalidContainerState {
+        /// The container id
+        id: ContainerId,
+        /// The container state
+        state: String,
+        /// The container exit code
+        exit_code: i32,
+        /// The last container log lines
+        logs: String,
+    },
+
+    /// Fail to build the image
+    #[error("Fail to build the image {image}\n{source}")]
+    BuildError {
+        /// The image name
+        image: String,
+        /// The source error
+        source: CommandError,
+    },
 
     /// The container is not healthy
     #[error("Container {0} is unhealthy")]
@@ -211,9 +391,80 @@ pub enum ContainerError {
     #[error("Container '{0}' cannot be removed")]
     ContainerCannotBeRemoved(ContainerId),
 
+    /// The container is not removed in time
+    #[error("Container {id} still exists after {timeout:?}")]
+    RemoveTimeout {
+        /// The container id
+        id: ContainerId,
+        /// The timeout
+        timeout: Duration,
+    },
+
     /// The container cannot reach wait condition
     #[error("Container {0} cannot reach wait condition {1}")]
-    WaitConditionUnreachable(ContainerId, WaitStrategy),
+    WaitConditionUnreachable(ContainerId, Box<WaitStrategy>),
+
+    /// Cannot create the HTTP client used to wait the container
+    #[error("Cannot create the HTTP client because {0}")]
+    HttpClientError(reqwest::Error),
+
+    /// The image is not available for the platform
+    #[error("Image {image} is not available for the platform {platform}, you could choose another platform with `RunOption::builder().with_platform(...)`")]
+    UnsupportedArchitecture {
+        /// The image
+        image: String,
+        /// The requested platform
+        platform: String,
+    },
+
+    /// The platform emulation is not available
+    #[error("The platform {platform} is not supported by the host, and the emulation is not available, you could register the QEMU emulators with `docker run --privileged --rm tonistiigi/binfmt --install all`")]
+    EmulationUnavailable {
+        /// The requested platform
+        platform: String,
+    },
+
+    /// The image does not exist locally
+    #[error("Image {0} not found, it may need to be pulled or built first")]
+    ImageNotFound(String),
+
+    /// Some options cannot be used together
+    #[error("Incompatible container options: {reason}")]
+    IncompatibleOptions {
+        /// The reason
+        reason: String,
+    },
+
+    /// The container does not exist
+    #[error("Container '{0}' not found")]
+    ContainerNotFound(String),
+
+    /// The container exists, but it's not running
+    #[error("Container '{name}' is not running, current state: {state}")]
+    ContainerNotRunning {
+        /// The container name or id
+        name: String,
+        /// The container state
+        state: ContainerStatus,
+    },
+
+    /// The file does not exist in the container
+    #[error("File '{path}' not found in container {id}")]
+    FileNotFound {
+        /// The container id
+        id: ContainerId,
+        /// The file path
+        path: String,
+    },
+
+    /// The directory does not exist in the container
+    #[error("Directory '{path}' not found in container {id}")]
+    DirectoryNotFound {
+        /// The container id
+        id: ContainerId,
+        /// The directory path
+        path: String,
+    },
 
     /// Fail to run error
     #[error(transparent)]
@@ -238,4 +489,102 @@ pub enum ContainerError {
     /// No network error
     #[error("No host network")]
     NoNetwork,
+
+    /// Pods are not supported by the runner
+    #[error("Cannot use the pod '{0}', pods are only supported by podman")]
+    PodUnsupported(String),
+
+    /// Invalid timestamp
+    #[error("Invalid timestamp '{0}'")]
+    InvalidTimestamp(String),
+
+    /// Expected a network with a name
+    #[error("Expected a network with a name, got {0}")]
+    ExpectedNetworkName(Box<Network>),
+
+    /// The container network mode does not provide an IP
+    #[error("Container {id} uses the '{network_mode}' network mode, no bridge IP available")]
+    UnsupportedNetworkMode {
+        /// The container id
+        id: ContainerId,
+        /// The network mode
+        network_mode: String,
+    },
+
+    /// Invalid IP address
+    #[error("Invalid IP address '{ip}' because {source}")]
+    InvalidIpAddress {
+        /// The IP
+        ip: String,
+        /// The source
+        source: std::net::AddrParseError,
+    },
+
+    /// Fail to join a background task, e.g. the image build
+    #[error("Fail to join the background task because {0}")]
+    JoinError(#[from] tokio::task::JoinError),
+}
+
+/// The report of the runners detection, see [`Runner::auto_with_report`]
+///
+/// It contains every rejected runner, with the rejection reason.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct RunnerReport {
+    /// The rejected runners
+    pub rejections: Vec<RunnerRejection>,
+}
+
+/// A rejected runner
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct RunnerRejection {
+    /// The runner command, e.g. `docker`
+    pub command: String,
+    /// The rejection reason
+    pub reason: RunnerError,
+}
+
+impl Display for RunnerReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "No runner available")?;
+        for RunnerRejection { command, reason } in &self.rejections {
+            write!(f, "\n - {command}: {reason}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for RunnerReport {}
+
+#[cfg(test)]
+mod tests {
+    use assert2::check;
+
+    use super::*;
+
+    #[test]
+    fn should_display_report() {
+        let report = RunnerReport {
+            rejections: vec![
+                RunnerRejection {
+                    command: String::from("docker"),
+                    reason: RunnerError::UnsupportedVersion {
+                        command: String::from("docker"),
+                        current: Version::new(1, 10),
+                        minimal: Version::new(1, 20),
+                    },
+                },
+                RunnerRejection {
+                    command: String::from("podman"),
+                    reason: RunnerError::CommandNotAvailable(String::from("podman")),
+                },
+            ],
+        };
+        let result = report.to_string();
+        check!(
+            result
+                == "No runner available\n - docker: docker version 1.10 expected to be ≥ that 1.20\n - podman: Command 'podman' not available"
+        );
+    }
 }
@@ -0,0 +1,89 @@
+use crate::version::Version;
+
+/// Docker API version of docker 19.03, that introduce the `--gpus` flag
+pub(super) const DOCKER_GPUS_MINIMAL_VERSION: Version = Version::new(1, 40);
+
+/// Docker API version of docker 25.0, that introduce the `--health-start-interval` flag
+pub(super) const DOCKER_HEALTH_START_INTERVAL_MINIMAL_VERSION: Version = Version::new(1, 44);
+
+/// Podman 5.0 supports the `--gpus` flag
+pub(super) const PODMAN_GPUS_MINIMAL_VERSION: Version = Version::new(5, 0);
+
+/// A runner feature that is not available with every runner, or every runner version
+///
+/// See [`Runner::supports`](super::Runner::supports)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum_macros::Display)]
+#[strum(serialize_all = "kebab-case")]
+#[non_exhaustive]
+pub enum Feature {
+    /// A compose command is available,
+    /// see [`Runner::compose_start`](super::Runner::compose_start)
+    Compose,
+
+    /// Pods are available, see [`Runner::create_pod`](super::Runner::create_pod)
+    Pod,
+
+    /// The `--gpus` flag is available
+    Gpus,
+
+    /// The `--health-start-interval` flag is available
+    HealthStartInterval,
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::check;
+    use rstest::rstest;
+
+    use super::*;
+    use std::sync::Arc;
+
+    use crate::runner::{Docker, Nerdctl, Podman, PodmanComposeCommand, Runner};
+
+    fn docker(version: Version) -> Runner {
+        Runner::Docker(Docker {
+            version,
+            compose_version: None,
+            rootless: false,
+            config: Arc::default(),
+        })
+    }
+
+    fn podman(version: Version) -> Runner {
+        Runner::Podman(Podman {
+            version,
+            compose_command: PodmanComposeCommand::PodmanCompose,
+            compose_version: Some(Version::new(1, 0)),
+            config: Arc::default(),
+        })
+    }
+
+    fn nerdctl() -> Runner {
+        Runner::Nerdctl(Nerdctl {
+            version: Version::new(1, 7),
+            config: Arc::default(),
+        })
+    }
+
+    #[rstest]
+    #[case::docker_compose(docker(Version::new(1, 43)), Feature::Compose, false)]
+    #[case::docker_pod(docker(Version::new(1, 43)), Feature::Pod, false)]
+    #[case::docker_gpus(docker(Version::new(1, 43)), Feature::Gpus, true)]
+    #[case::old_docker_gpus(docker(Version::new(1, 39)), Feature::Gpus, false)]
+    #[case::docker_health(docker(Version::new(1, 44)), Feature::HealthStartInterval, true)]
+    #[case::old_docker_health(docker(Version::new(1, 43)), Feature::HealthStartInterval, false)]
+    #[case::podman_compose(podman(Version::new(4, 9)), Feature::Compose, true)]
+    #[case::podman_pod(podman(Version::new(4, 9)), Feature::Pod, true)]
+    #[case::podman_gpus(podman(Version::new(5, 0)), Feature::Gpus, true)]
+    #[case::old_podman_gpus(podman(Version::new(4, 9)), Feature::Gpus, false)]
+    #[case::podman_health(podman(Version::new(5, 0)), Feature::HealthStartInterval, false)]
+    #[case::nerdctl_compose(nerdctl(), Feature::Compose, true)]
+    #[case::nerdctl_pod(nerdctl(), Feature::Pod, false)]
+    fn should_check_feature(
+        #[case] runner: Runner,
+        #[case] feature: Feature,
+        #[case] expected: bool,
+    ) {
+        check!(runner.supports(feature) == expected);
+    }
+}
@@ -3,30 +3,65 @@ use std::collections::HashMap;
 use std::env;
 use std::fmt::{Debug, Display};
 use std::net::{IpAddr, Ipv4Addr, SocketAddr};
-use std::path::Path;
-use std::time::Duration;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, SystemTime};
 
 use async_trait::async_trait;
 use indexmap::IndexMap;
 use serde::de::DeserializeOwned;
-use tokio::net::TcpStream;
+use tokio::net::{TcpStream, UdpSocket};
 use tokio::sync::mpsc;
-use tracing::{debug, info, trace, warn};
+use tokio::task::JoinHandle;
+use tracing::{debug, error, info, trace, warn};
+use ulid::Ulid;
 
-use crate::cmd::Cmd;
+use crate::cmd::{Cmd, CommandError};
 use crate::io::StdIoKind;
+use crate::timestamp::parse_rfc3339;
 use crate::{
-    ContainerHealth, ContainerId, ContainerProcess, ContainerState, ContainerStatus, ExposedPort,
-    HealthCheck, HostContainer, Ip, IpamNetworkConfig, Network, NetworkDetails, NetworkInfo, Port,
-    RunnableContainer, Volume, WaitStrategy,
+    parse_stats_line, BuildContext, ContainerConfig, ContainerHealth, ContainerId,
+    ContainerInspect, ContainerProcess, ContainerState, ContainerStats, ContainerStatus,
+    ExecOutput, ExposedPort, HealthCheck, HealthLogEntry, HostContainer, ImageInspect, ImageName,
+    ImageReference, Ip, IpamNetworkConfig, LogsSince, Network, NetworkDetails, NetworkInfo, Port,
+    PortProtocol, RunnableContainer, TemporaryContentFile, Volume, VolumeName, WaitStrategy,
+    STATS_FORMAT,
 };
 
-use super::{ContainerError, RunOption};
+use super::{CgroupNamespace, ContainerError, LabelFilter, RunOption};
+
+/// The number of log lines provided when a container stops unexpectedly
+const LAST_LOG_LINES: usize = 20;
+
+/// The docker socket path, used on the host by default, and always inside the container
+const DOCKER_SOCKET: &str = "/var/run/docker.sock";
+
+/// The timeout of a request while waiting for an HTTP success
+const HTTP_WAIT_REQUEST_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// The interval between two checks while waiting for a container removal
+const WAIT_REMOVED_INTERVAL: Duration = Duration::from_millis(100);
+
+/// The multi-platform image used to check the platform emulation
+const EMULATION_CHECK_IMAGE: &str = "docker.io/alpine";
 
 #[async_trait]
 pub(crate) trait InnerRunner: Display + Debug + Send + Sync {
     fn command(&self) -> Cmd<'static>;
 
+    /// The default stop timeout (in seconds), if `None` the runner default is used
+    fn stop_timeout(&self) -> Option<u16>;
+
+    /// The host address override, if `None` the host address is detected
+    fn host_address(&self) -> Option<IpAddr>;
+
+    /// Whether the runner daemon runs in rootless mode
+    ///
+    /// In rootless mode, the published ports are reachable on localhost
+    fn is_rootless(&self) -> bool {
+        false
+    }
+
     #[tracing::instrument(level = "debug", skip(self), fields(runner = %self))]
     async fn ps(&self, name: &str) -> Result<Option<ContainerProcess>, ContainerError> {
         let mut cmd = self.command();
@@ -39,15 +74,119 @@ pub(crate) trait InnerRunner: Display + Debug + Send + Sync {
             "--format={{json .}}",
         ]);
 
-        let containers = cmd.json_stream::<ContainerProcess>().await?;
+        let containers = cmd.json_list::<ContainerProcess>().await?;
         let result = containers.into_iter().find(|it| it.names.contains(name));
         Ok(result)
     }
 
+    #[tracing::instrument(level = "debug", skip(self, id), fields(runner = %self, id = %id))]
+    async fn ps_by_id(&self, id: ContainerId) -> Result<Option<ContainerProcess>, ContainerError> {
+        let mut cmd = self.command();
+        cmd.push_args(["ps", "--all", "--no-trunc", "--filter"]);
+        cmd.push_arg(format!("id={id}"));
+        cmd.push_arg("--format={{json .}}");
+
+        let containers = cmd.json_list::<ContainerProcess>().await?;
+        let result = containers.into_iter().find(|it| it.id == id);
+        Ok(result)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), fields(runner = %self))]
+    async fn attach_existing(
+        &self,
+        name_or_id: &str,
+    ) -> Result<(ContainerId, ImageReference, Vec<ExposedPort>), ContainerError> {
+        let mut cmd = self.command();
+        cmd.push_args(["container", "inspect", name_or_id]);
+        let inspect = match cmd.json::<Vec<ContainerInspect>>().await {
+            Ok(result) => result.into_iter().next(),
+            Err(err) if is_missing_container(&err) => None,
+            Err(err) => return Err(err.into()),
+        };
+        let Some(inspect) = inspect else {
+            return Err(ContainerError::ContainerNotFound(name_or_id.to_string()));
+        };
+        let state = inspect.state.status;
+        if !state.is_running() {
+            return Err(ContainerError::ContainerNotRunning {
+                name: name_or_id.to_string(),
+                state,
+            });
+        }
+        let image_ref = inspect.image_ref()?;
+
+        // The published ports, without the duplicates (e.g. IPv4 and IPv6 bindings)
+        let ports = self
+            .ps_by_id(inspect.id)
+            .await?
+            .map(|process| process.ports)
+            .unwrap_or_default();
+        let mut port_mappings = Vec::<ExposedPort>::new();
+        for port in ports {
+            let Some(host_port) = port.host_port else {
+                continue;
+            };
+            let exists = port_mappings
+                .iter()
+                .any(|it| it.container_port == port.container_port && it.protocol == port.protocol);
+            if !exists {
+                let mapping =
+                    ExposedPort::fixed(port.container_port, host_port).with_protocol(port.protocol);
+                port_mappings.push(mapping);
+            }
+        }
+
+        Ok((inspect.id, image_ref, port_mappings))
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), fields(runner = %self))]
+    async fn list_running_containers(
+        &self,
+        filter: &LabelFilter,
+    ) -> Result<Vec<ContainerProcess>, ContainerError> {
+        let mut cmd = self.command();
+        cmd.push_args(["ps", "--no-trunc", "--format={{json .}}"]);
+        filter.push_args(&mut cmd);
+        let containers = cmd.json_list::<ContainerProcess>().await?;
+        Ok(containers)
+    }
+
     #[tracing::instrument(level = "debug", skip(self), fields(runner = %self))]
     async fn create_network(&self, name: &str) -> Result<(), ContainerError> {
         let mut cmd = self.command();
         cmd.push_args(["network", "create", name]);
+        match cmd.status().await {
+            Ok(_) => Ok(()),
+            Err(err) if is_network_already_exists(&err) => {
+                debug!(%name, "Network already exists");
+                Ok(())
+            }
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// If the runner supports pods (see `podman pod`)
+    fn supports_pod(&self) -> bool {
+        false
+    }
+
+    /// The runner API socket on the host, see [`RunOption::with_docker_socket`](super::RunOptionBuilder::with_docker_socket)
+    ///
+    /// By default, it's the `DOCKER_HOST` unix socket, or `/var/run/docker.sock`
+    fn socket_path(&self) -> PathBuf {
+        self.get_docker_host()
+            .as_deref()
+            .and_then(|host| host.strip_prefix("unix://"))
+            .map_or_else(|| PathBuf::from(DOCKER_SOCKET), PathBuf::from)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), fields(runner = %self))]
+    async fn create_pod(&self, name: &str) -> Result<(), ContainerError> {
+        if !self.supports_pod() {
+            return Err(ContainerError::PodUnsupported(name.to_string()));
+        }
+        let mut cmd = self.command();
+        cmd.push_args(["pod", "create", name]);
         cmd.status().await?;
         Ok(())
     }
@@ -60,70 +199,237 @@ pub(crate) trait InnerRunner: Display + Debug + Send + Sync {
         Ok(())
     }
 
+    #[tracing::instrument(level = "debug", skip(self, build), fields(runner = %self, image = %image))]
+    async fn build_image(
+        &self,
+        image: &ImageReference,
+        build: &BuildContext,
+    ) -> Result<(), ContainerError> {
+        let mut cmd = self.command();
+        cmd.push_arg("build");
+        cmd.push_args(build.to_args(image));
+
+        info!(%image, "🔨 Building image");
+        cmd.status()
+            .await
+            .map_err(|source| ContainerError::BuildError {
+                image: image.to_string(),
+                source,
+            })?;
+        info!(%image, "🔨 Image built");
+
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, build), fields(runner = %self, image = %image))]
+    async fn watch_build(
+        &self,
+        image: &ImageReference,
+        build: &BuildContext,
+        buffer_size: usize,
+    ) -> Result<
+        (
+            mpsc::Receiver<String>,
+            JoinHandle<Result<(), ContainerError>>,
+        ),
+        ContainerError,
+    > {
+        let mut cmd = self.command();
+        cmd.push_arg("build");
+        cmd.push_args(build.to_args(image));
+
+        info!(%image, "🔨 Building image");
+        let (tx, rx) = mpsc::channel(buffer_size);
+        let image = image.to_string();
+        let build = tokio::spawn(async move {
+            match cmd.watch_io(StdIoKind::Both, tx).await {
+                Ok(_) => {
+                    info!(%image, "🔨 Image built");
+                    Ok(())
+                }
+                Err(source) => {
+                    warn!(%image, %source, "⚠️ Fail to build image");
+                    Err(ContainerError::BuildError { image, source })
+                }
+            }
+        });
+
+        Ok((rx, build))
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, image), fields(runner = %self, image = %image))]
+    async fn inspect_image(&self, image: &ImageName) -> Result<ImageInspect, ContainerError> {
+        let mut cmd = self.command();
+        cmd.push_args(["image", "inspect"]);
+        cmd.push_arg(image.to_string());
+
+        let result = match cmd.json::<Vec<ImageInspect>>().await {
+            Ok(result) => result,
+            Err(err) if is_missing_image(&err) => {
+                return Err(ContainerError::ImageNotFound(image.to_string()));
+            }
+            Err(err) => return Err(err.into()),
+        };
+        result
+            .into_iter()
+            .next()
+            .ok_or_else(|| ContainerError::ImageNotFound(image.to_string()))
+    }
+
     #[tracing::instrument(level = "debug", skip(self, option), fields(runner = %self))]
     async fn create_and_start(
         &self,
         option: CreateAndStartOption<'_>,
     ) -> Result<ContainerId, ContainerError> {
+        option.validate()?;
+        if let (true, Some(platform)) = (option.check_emulation, option.platform) {
+            self.check_emulation(platform).await?;
+        }
+        if let Some(build) = option.build {
+            self.build_image(option.image, build).await?;
+        }
+
         let mut cmd = self.command();
         cmd.push_args(["run", "--detach"]);
+        self.push_docker_socket_args(&option, &mut cmd)?;
+        option.push_run_args(&mut cmd).await?;
 
-        // Remove
-        if option.remove {
-            cmd.push_arg("--rm");
-        }
+        // Run
+        let descriptor = &option.descriptor;
+        info!(image = %descriptor, "🚀 Launching container");
+        let stdout = cmd
+            .result()
+            .await
+            .map_err(|err| check_unsupported_architecture(descriptor, err))?;
+        let id = stdout.trim().parse::<ContainerId>()?;
 
-        // Name
-        if let Some(name) = option.name {
-            cmd.push_args(["--name", name]);
+        // Additional networks
+        for network in option.additional_networks {
+            if let Err(err) = self.connect_network(id, network).await {
+                warn!(%id, %network, "Fail to connect the network, removing the container");
+                if let Err(remove_err) = self.stop_and_remove(id).await {
+                    error!(%id, "Fail to remove the container because {remove_err}");
+                }
+                return Err(err);
+            }
         }
 
-        // Env. vars.
-        for (key, value) in option.env {
-            let env_var = format!("{key}={value}");
-            cmd.push_args(["--env", &env_var]);
-        }
+        Ok(id)
+    }
 
-        // Published ports
-        for port_mapping in option.ports {
-            let publish = port_mapping.to_publish().await;
-            cmd.push_args(["--publish", &publish]);
+    /// Check the platform could run on the host, natively or with an emulation
+    #[tracing::instrument(level = "debug", skip(self), fields(runner = %self))]
+    async fn check_emulation(&self, platform: &str) -> Result<(), ContainerError> {
+        if is_native_platform(platform, env::consts::ARCH) {
+            return Ok(());
         }
-
-        // Health check args.
-        if let Some(hc) = &option.health_check {
-            cmd.push_args(hc.to_vec());
+        let mut cmd = self.command();
+        cmd.push_args([
+            "run",
+            "--rm",
+            "--platform",
+            platform,
+            EMULATION_CHECK_IMAGE,
+            "true",
+        ]);
+        match cmd.status().await {
+            Ok(_) => Ok(()),
+            Err(err) if is_exec_format_error(&err) => {
+                warn!(%platform, "⚠️ The platform emulation is not available");
+                Err(ContainerError::EmulationUnavailable {
+                    platform: platform.to_string(),
+                })
+            }
+            Err(err) => Err(check_unsupported_architecture(EMULATION_CHECK_IMAGE, err)),
         }
+    }
 
-        // Network
-        let network = option.network.cmd_arg();
-        cmd.push_arg(network.as_ref());
-
-        // Volumes
-        for volume in option.volumes {
+    /// Bind mount the runner socket (read-only) if requested
+    fn push_docker_socket_args(
+        &self,
+        option: &CreateAndStartOption<'_>,
+        cmd: &mut Cmd<'_>,
+    ) -> Result<(), ContainerError> {
+        if option.docker_socket {
+            let volume = Volume::BindMount {
+                container: PathBuf::from(DOCKER_SOCKET),
+                host: self.socket_path(),
+                readonly: true,
+            };
             cmd.push_arg("--mount");
-            cmd.push_arg(&volume.mount_arg()?);
+            cmd.push_arg(volume.mount_arg()?);
         }
+        Ok(())
+    }
 
-        // Entrypoint
-        if let Some(entrypoint) = option.entrypoint {
-            cmd.push_args(["--entrypoint", entrypoint]);
+    #[tracing::instrument(level = "debug", skip(self, option), fields(runner = %self))]
+    async fn run_once(
+        &self,
+        option: CreateAndStartOption<'_>,
+    ) -> Result<(i32, String), ContainerError> {
+        option.validate()?;
+        if let (true, Some(platform)) = (option.check_emulation, option.platform) {
+            self.check_emulation(platform).await?;
+        }
+        if let Some(build) = option.build {
+            self.build_image(option.image, build).await?;
         }
 
-        // Descriptor (name:tag or other alternatives)
-        let descriptor = &option.descriptor;
-        cmd.push_arg(descriptor);
+        let mut cmd = self.command();
+        cmd.push_arg("run");
+        self.push_docker_socket_args(&option, &mut cmd)?;
+        option.push_run_args(&mut cmd).await?;
 
-        // Command
-        let command_args = option.command;
-        cmd.push_args(command_args);
+        let descriptor = &option.descriptor;
+        info!(image = %descriptor, "🚀 Running container once");
+        let (exit_code, stdout) = cmd
+            .exit_code_and_result()
+            .await
+            .map_err(|err| check_unsupported_architecture(descriptor, err))?;
+        info!(image = %descriptor, exit_code, "🏁 Container exited");
+
+        Ok((exit_code, stdout))
+    }
 
-        // Run
-        info!(image = %descriptor, "🚀 Launching container");
-        let stdout = cmd.result().await?;
-        let id = stdout.trim().parse::<ContainerId>()?;
+    #[tracing::instrument(level = "debug", skip(self, id), fields(runner = %self, id = %id))]
+    async fn connect_network(
+        &self,
+        id: ContainerId,
+        network: &Network,
+    ) -> Result<(), ContainerError> {
+        let Some(name) = network.name() else {
+            return Err(ContainerError::ExpectedNetworkName(Box::new(
+                network.clone(),
+            )));
+        };
+        let mut cmd = self.command();
+        cmd.push_args(["network", "connect", name]);
+        cmd.push_arg(id);
+        cmd.status().await?;
+        info!(%id, %network, "🔌 Container connected to network");
+        Ok(())
+    }
 
-        Ok(id)
+    /// Connect a reused container to the networks it's not connected to yet
+    #[tracing::instrument(level = "debug", skip(self, id), fields(runner = %self, id = %id))]
+    async fn connect_missing_networks(
+        &self,
+        id: ContainerId,
+        networks: &[Network],
+    ) -> Result<(), ContainerError> {
+        if networks.is_empty() {
+            return Ok(());
+        }
+        let connected = self.inspect_container_networks(id).await?;
+        for network in networks {
+            let is_connected = network
+                .name()
+                .is_some_and(|name| connected.contains_key(name));
+            if !is_connected {
+                self.connect_network(id, network).await?;
+            }
+        }
+        Ok(())
     }
 
     #[tracing::instrument(level = "debug", skip(self, id), fields(runner = %self, id = %id))]
@@ -134,16 +440,36 @@ pub(crate) trait InnerRunner: Display + Debug + Send + Sync {
         let mut cmd = self.command();
         cmd.push_args(["inspect", &format!("--format={{{{json {json_path}}}}}")]);
         cmd.push_arg(id);
-        let result = cmd.json::<R>().await?;
+        let result = cmd.json_or_default::<R>().await?;
         Ok(result)
     }
 
+    #[tracing::instrument(level = "debug", skip(self, id), fields(runner = %self, id = %id))]
+    async fn inspect_raw(&self, id: ContainerId) -> Result<serde_json::Value, ContainerError> {
+        let mut cmd = self.command();
+        cmd.push_arg("inspect");
+        cmd.push_arg(id);
+        let result = cmd.json::<Vec<serde_json::Value>>().await?;
+        Ok(result.into_iter().next().unwrap_or_default())
+    }
+
     #[tracing::instrument(level = "debug", skip(self, id), fields(runner = %self, id = %id))]
     async fn port(&self, id: ContainerId, container_port: Port) -> Result<Port, ContainerError> {
+        self.protocol_port(id, container_port, PortProtocol::Tcp)
+            .await
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, id), fields(runner = %self, id = %id))]
+    async fn protocol_port(
+        &self,
+        id: ContainerId,
+        container_port: Port,
+        protocol: PortProtocol,
+    ) -> Result<Port, ContainerError> {
         let mut cmd = self.command();
         cmd.push_arg("port");
         cmd.push_arg(id);
-        cmd.push_arg(container_port);
+        cmd.push_arg(format!("{container_port}/{protocol}"));
         let output = cmd.result().await?;
         parse_port(&output).ok_or_else(|| {
             warn!( %id, %container_port, "Bound port not found\n{cmd}\noutput: '{output}'");
@@ -185,6 +511,29 @@ pub(crate) trait InnerRunner: Display + Debug + Send + Sync {
         self.inspect(id, ".State").await
     }
 
+    #[tracing::instrument(level = "debug", skip(self, id), fields(runner = %self, id = %id))]
+    async fn container_config(&self, id: ContainerId) -> Result<ContainerConfig, ContainerError> {
+        self.inspect(id, ".Config").await
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, id), fields(runner = %self, id = %id))]
+    async fn created_at(&self, id: ContainerId) -> Result<SystemTime, ContainerError> {
+        let created = self.inspect::<String>(id, ".Created").await?;
+        parse_rfc3339(&created).ok_or(ContainerError::InvalidTimestamp(created))
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, id), fields(runner = %self, id = %id))]
+    async fn started_at(&self, id: ContainerId) -> Result<SystemTime, ContainerError> {
+        let state = self.full_status(id).await?;
+        let started_at = state.started_at.unwrap_or_default();
+        parse_rfc3339(&started_at).ok_or(ContainerError::InvalidTimestamp(started_at))
+    }
+
+    async fn exit_code(&self, id: ContainerId) -> Result<Option<i32>, ContainerError> {
+        let state = self.full_status(id).await?;
+        Ok(state.exit_code())
+    }
+
     #[tracing::instrument(level = "debug", skip(self), fields(runner = %self))]
     async fn network_ip(
         &self,
@@ -195,6 +544,26 @@ pub(crate) trait InnerRunner: Display + Debug + Send + Sync {
         networks.remove(network).ok_or(ContainerError::NoNetwork)
     }
 
+    #[tracing::instrument(level = "debug", skip(self), fields(runner = %self))]
+    async fn bridge_ip(&self, id: ContainerId) -> Result<Option<IpAddr>, ContainerError> {
+        let network_mode = self
+            .inspect::<String>(id, ".HostConfig.NetworkMode")
+            .await?;
+        if ["host", "none"].contains(&network_mode.as_str()) {
+            return Err(ContainerError::UnsupportedNetworkMode { id, network_mode });
+        }
+        let ip = self
+            .inspect::<String>(id, ".NetworkSettings.IPAddress")
+            .await?;
+        if ip.is_empty() {
+            return Ok(None);
+        }
+        let ip = ip
+            .parse()
+            .map_err(|source| ContainerError::InvalidIpAddress { ip, source })?;
+        Ok(Some(ip))
+    }
+
     #[tracing::instrument(level = "debug", skip(self), fields(runner = %self))]
     async fn inspect_container_networks(
         &self,
@@ -222,10 +591,33 @@ pub(crate) trait InnerRunner: Display + Debug + Send + Sync {
     }
 
     #[tracing::instrument(level = "debug", skip(self), fields(runner = %self))]
-    async fn list_custom_networks(&self) -> Result<Vec<NetworkInfo>, ContainerError> {
+    async fn list_networks(
+        &self,
+        filter: &LabelFilter,
+    ) -> Result<Vec<NetworkInfo>, ContainerError> {
         let mut cmd = self.command();
         cmd.push_args(["network", "ls", "--no-trunc", "--format={{json .}}"]);
-        let mut result = cmd.json_stream::<NetworkInfo>().await?;
+        filter.push_args(&mut cmd);
+        let result = cmd.json_stream::<NetworkInfo>().await?;
+        Ok(result)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), fields(runner = %self))]
+    async fn list_volumes(&self, filter: &LabelFilter) -> Result<Vec<VolumeName>, ContainerError> {
+        let mut cmd = self.command();
+        cmd.push_args(["volume", "ls", "--format={{.Name}}"]);
+        filter.push_args(&mut cmd);
+        let stdout = cmd.result().await?;
+        let result = stdout
+            .lines()
+            .filter_map(|line| line.trim().parse().ok())
+            .collect();
+        Ok(result)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), fields(runner = %self))]
+    async fn list_custom_networks(&self) -> Result<Vec<NetworkInfo>, ContainerError> {
+        let mut result = self.list_networks(&LabelFilter::default()).await?;
         result.retain(|x| ["bridge", "host", "none"].contains(&x.name.as_str()));
         Ok(result)
     }
@@ -235,10 +627,7 @@ pub(crate) trait InnerRunner: Display + Debug + Send + Sync {
         &self,
         network_id: ContainerId,
     ) -> Result<Vec<IpamNetworkConfig>, ContainerError> {
-        let results = self
-            .inspect::<Option<Vec<IpamNetworkConfig>>>(network_id, ".IPAM.Config")
-            .await?;
-        Ok(results.unwrap_or_default())
+        self.inspect(network_id, ".IPAM.Config").await
     }
 
     #[tracing::instrument(level = "debug", skip(self, id), fields(runner = %self, id = %id))]
@@ -247,21 +636,43 @@ pub(crate) trait InnerRunner: Display + Debug + Send + Sync {
         id: ContainerId,
         wait_condition: &WaitStrategy,
         interval: Duration, // TODO could have a more flexible type
+        log_buffer_size: usize,
     ) -> Result<(), ContainerError> {
         if let WaitStrategy::LogMatch { io, matcher } = wait_condition {
-            let mut rx = self.watch_logs(id, *io).await?;
+            let mut rx = self.watch_logs(id, *io, log_buffer_size).await?;
             while let Some(line) = rx.recv().await {
                 trace!("Log: {line}");
                 if matcher.matches(&line) {
+                    info!(%id, "💚 log matched");
                     return Ok(());
                 }
             }
             return Err(ContainerError::WaitConditionUnreachable(
                 id,
-                wait_condition.clone(),
+                Box::new(wait_condition.clone()),
             ));
         }
 
+        // The HTTP client is created once, with a short timeout for each request
+        let http_client = if let WaitStrategy::HttpSuccess {
+            require_valid_certs,
+            ..
+        }
+        | WaitStrategy::HttpSuccessOnHostPort {
+            require_valid_certs,
+            ..
+        } = wait_condition
+        {
+            let client = reqwest::ClientBuilder::new()
+                .danger_accept_invalid_certs(!require_valid_certs)
+                .timeout(HTTP_WAIT_REQUEST_TIMEOUT)
+                .build()
+                .map_err(ContainerError::HttpClientError)?;
+            Some(client)
+        } else {
+            None
+        };
+
         // Other cases
         loop {
             match wait_condition {
@@ -279,36 +690,33 @@ pub(crate) trait InnerRunner: Display + Debug + Send + Sync {
                 }
                 WaitStrategy::HttpSuccess {
                     https,
-                    require_valid_certs,
                     path,
                     container_port,
+                    ..
                 } => {
-                    let Ok(host_port) = self.port(id, *container_port).await else {
-                        info!(%container_port,"Port not bind, will retry later");
-                        continue;
-                    };
-                    let scheme = if *https { "https" } else { "http" };
-                    let url = format!(
-                        "{scheme}://127.0.0.1:{host_port}/{}",
-                        path.trim_start_matches('/')
-                    );
-                    let Ok(client) = reqwest::ClientBuilder::new()
-                        .danger_accept_invalid_certs(!require_valid_certs)
-                        .build()
-                    else {
-                        warn!(%url,"Could not create new client, will retry later");
-                        continue;
-                    };
-                    let Ok(response) = client.get(&url).send().await else {
-                        warn!(%url,"Fail to get the URL, will retry later");
-                        continue;
-                    };
-                    let status = response.status();
-                    if status.is_success() {
-                        info!(%id, %status, "💚 {url} successful");
-                        break;
+                    if let Some(client) = &http_client {
+                        if self
+                            .check_http_success(id, client, *https, path, *container_port)
+                            .await
+                        {
+                            break;
+                        }
+                    }
+                }
+                WaitStrategy::HttpSuccessOnHostPort {
+                    https,
+                    path,
+                    host_port,
+                    ..
+                } => {
+                    if let Some(client) = &http_client {
+                        if self
+                            .check_http_url_success(id, client, *https, path, *host_port)
+                            .await
+                        {
+                            break;
+                        }
                     }
-                    debug!(%status, "{url} not yet ready, will retry later");
                 }
                 WaitStrategy::ScanPort {
                     container_port,
@@ -332,6 +740,54 @@ pub(crate) trait InnerRunner: Display + Debug + Send + Sync {
                     }
                     debug!(%id, %container_port, %host_port, "Port {container_port} not yet available, will retry later");
                 }
+                WaitStrategy::ScanUdpPort {
+                    container_port,
+                    timeout,
+                    probe,
+                } => {
+                    if let Ok(host_port) = self
+                        .protocol_port(id, *container_port, PortProtocol::Udp)
+                        .await
+                    {
+                        let addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), host_port.0);
+                        match probe_udp_port(addr, probe, *timeout).await {
+                            Ok(()) => {
+                                info!(%id, %container_port, %host_port, "💚 UDP port {container_port} available");
+                                break;
+                            }
+                            Err(err) => {
+                                debug!(%id, %container_port, %host_port, %err, "UDP port {container_port} not yet available, will retry later");
+                            }
+                        }
+                    } else {
+                        info!(%container_port, "UDP port not bind, will retry later");
+                    }
+                }
+                WaitStrategy::Grpc {
+                    container_port,
+                    service,
+                } => {
+                    if self
+                        .check_grpc_health(id, *container_port, service.as_deref())
+                        .await
+                    {
+                        info!(%id, %container_port, "💚 gRPC service serving");
+                        break;
+                    }
+                    debug!(%id, %container_port, "gRPC service not yet serving, will retry later");
+                }
+                WaitStrategy::FileExists {
+                    path,
+                    interval: file_interval,
+                } => {
+                    if self.check_file_exists(id, path).await {
+                        info!(%id, ?path, "💚 file exists");
+                        break;
+                    }
+                    debug!(%id, ?path, "File does not exist yet, will retry later");
+                    tokio::time::sleep(*file_interval).await;
+                    continue;
+                }
                 WaitStrategy::None => {
                     break;
                 }
@@ -373,7 +829,9 @@ pub(crate) trait InnerRunner: Display + Debug + Send + Sync {
 
     #[tracing::instrument(skip(self),fields(runner = %self))]
     async fn host(&self) -> Result<Ip, ContainerError> {
-        if self.is_inside_container() {
+        if let Some(address) = self.host_address() {
+            Ok(Ip(address))
+        } else if self.is_inside_container() && !self.is_rootless() {
             self.default_gateway_ip().await
         } else {
             Ok(Ip(IpAddr::from(Ipv4Addr::LOCALHOST)))
@@ -387,8 +845,8 @@ pub(crate) trait InnerRunner: Display + Debug + Send + Sync {
         let networks = self.inspect_container_networks(host_id).await?;
         // Filter when values are defined
         let networks = networks
-            .into_iter()
-            .filter_map(|(_, network)| network.id.zip(network.gateway))
+            .into_values()
+            .filter_map(|network| network.id.zip(network.gateway))
             .collect::<Vec<_>>();
         for (network_id, net_gateway) in networks {
             let containers = self.inspect_network_containers(network_id).await?;
@@ -411,64 +869,213 @@ pub(crate) trait InnerRunner: Display + Debug + Send + Sync {
         &self,
         id: ContainerId,
         io: StdIoKind,
+        buffer_size: usize,
     ) -> Result<mpsc::Receiver<String>, ContainerError> {
         let mut cmd = self.command();
         cmd.push_args(["logs", "--follow"]);
         cmd.push_arg(id);
 
-        let (tx, rx) = mpsc::channel(256);
+        let (tx, rx) = mpsc::channel(buffer_size);
         tokio::spawn(async move { cmd.watch_io(io, tx).await });
 
         Ok(rx)
     }
 
-    async fn check_healthy(&self, id: ContainerId) -> Result<bool, ContainerError> {
-        let state = self.full_status(id).await?;
-        if !matches!(
-            state.status,
-            ContainerStatus::Restarting | ContainerStatus::Running
-        ) {
-            warn!(%id, ?state, "✋ The container not seems to run");
-            let state = format!("{:?}", state.status);
-            return Err(ContainerError::InvalidContainerState(id, state));
-        }
-        match state.health.status {
-            ContainerHealth::Healthy => Ok(true),
-            ContainerHealth::Unhealthy => {
-                info!(%id, "🚨 unhealthy");
-                Err(ContainerError::UnhealthyContainer(id))
-            }
-            ContainerHealth::Starting => {
-                // TODO use another way to display logs (like tokio channel)
-                if let Some(last_log) = state.health.log.unwrap_or_default().last() {
-                    trace!(%id, "Last health check log\n\t{}", last_log);
-                }
-                Ok(false)
-            }
-            ContainerHealth::Unknown => {
-                warn!(%id, ?state, "🩺 The container does not have health check");
-                Err(ContainerError::UnknownContainerHealth(id))
-            }
-        }
-    }
-
-    async fn check_for_state(
+    async fn watch_stats(
         &self,
         id: ContainerId,
-        state: ContainerStatus,
-    ) -> Result<bool, ContainerError> {
-        let status = self.full_status(id).await?;
-        Ok(status.status == state)
-    }
-
-    #[tracing::instrument(level = "debug", skip(self, id), fields(runner = %self, id = %id))]
-    async fn rm(&self, id: ContainerId) -> Result<(), ContainerError> {
+        buffer_size: usize,
+    ) -> Result<mpsc::Receiver<ContainerStats>, ContainerError> {
         let mut cmd = self.command();
-        cmd.push_arg("rm");
+        cmd.push_args(["stats", "--format", STATS_FORMAT]);
         cmd.push_arg(id);
-        let status = cmd.status().await?;
 
-        if status.success() {
+        let (line_tx, mut line_rx) = mpsc::channel(buffer_size);
+        tokio::spawn(async move { cmd.watch_io(StdIoKind::Out, line_tx).await });
+
+        let (tx, rx) = mpsc::channel(buffer_size);
+        tokio::spawn(async move {
+            while let Some(line) = line_rx.recv().await {
+                let stats = match parse_stats_line(&line) {
+                    Some(Ok(stats)) => stats,
+                    Some(Err(err)) => {
+                        warn!(%id, %err, %line, "Fail to parse stats");
+                        continue;
+                    }
+                    None => continue,
+                };
+                if tx.send(stats).await.is_err() {
+                    // The receiver is dropped
+                    break;
+                }
+            }
+        });
+
+        Ok(rx)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, id), fields(runner = %self, id = %id))]
+    async fn tail_logs(&self, id: ContainerId, lines: usize) -> Result<String, ContainerError> {
+        let mut cmd = self.command();
+        cmd.push_arg("logs");
+        cmd.push_arg(format!("--tail={lines}"));
+        cmd.push_arg(id);
+        let result = cmd.result_with_stderr().await?;
+        Ok(result)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, id), fields(runner = %self, id = %id))]
+    async fn logs_since(
+        &self,
+        id: ContainerId,
+        since: LogsSince,
+    ) -> Result<String, ContainerError> {
+        let mut cmd = self.command();
+        cmd.push_arg("logs");
+        cmd.push_arg(format!("--since={}", since.to_arg()));
+        cmd.push_arg(id);
+        let result = cmd.result_with_stderr().await?;
+        Ok(result)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, id), fields(runner = %self, id = %id))]
+    async fn health_log(&self, id: ContainerId) -> Result<Vec<HealthLogEntry>, ContainerError> {
+        let state = self.full_status(id).await?;
+        Ok(state.health.log.unwrap_or_default())
+    }
+
+    async fn check_healthy(&self, id: ContainerId) -> Result<bool, ContainerError> {
+        let state = self.full_status(id).await?;
+        if !state.status.is_running() {
+            warn!(%id, ?state, "✋ The container not seems to run");
+            let logs = match self.tail_logs(id, LAST_LOG_LINES).await {
+                Ok(logs) => logs,
+                Err(err) => {
+                    warn!(%id, %err, "Fail to retrieve the container logs");
+                    String::new()
+                }
+            };
+            return Err(ContainerError::InvalidContainerState {
+                id,
+                state: format!("{:?}", state.status),
+                exit_code: state.exit_code.unwrap_or_default(),
+                logs,
+            });
+        }
+        match state.health.status {
+            ContainerHealth::Healthy => Ok(true),
+            ContainerHealth::Unhealthy => {
+                info!(%id, "🚨 unhealthy");
+                Err(ContainerError::UnhealthyContainer(id))
+            }
+            ContainerHealth::Starting => {
+                // TODO use another way to display logs (like tokio channel)
+                if let Some(last_log) = state.health.log.unwrap_or_default().last() {
+                    trace!(%id, "Last health check log\n\t{}", last_log);
+                }
+                Ok(false)
+            }
+            ContainerHealth::Unknown => {
+                warn!(%id, ?state, "🩺 The container does not have health check");
+                Err(ContainerError::UnknownContainerHealth(id))
+            }
+        }
+    }
+
+    async fn check_http_success(
+        &self,
+        id: ContainerId,
+        client: &reqwest::Client,
+        https: bool,
+        path: &str,
+        container_port: Port,
+    ) -> bool {
+        let Ok(host_port) = self.port(id, container_port).await else {
+            info!(%container_port, "Port not bind, will retry later");
+            return false;
+        };
+        self.check_http_url_success(id, client, https, path, host_port)
+            .await
+    }
+
+    async fn check_http_url_success(
+        &self,
+        id: ContainerId,
+        client: &reqwest::Client,
+        https: bool,
+        path: &str,
+        host_port: Port,
+    ) -> bool {
+        let scheme = if https { "https" } else { "http" };
+        let url = format!(
+            "{scheme}://127.0.0.1:{host_port}/{}",
+            path.trim_start_matches('/')
+        );
+        let response = match client.get(&url).send().await {
+            Ok(response) => response,
+            Err(err) => {
+                warn!(%url, %err, "Fail to get the URL, will retry later");
+                return false;
+            }
+        };
+        let status = response.status();
+        if status.is_success() {
+            info!(%id, %status, "💚 {url} successful");
+            return true;
+        }
+        debug!(%status, "{url} not yet ready, will retry later");
+        false
+    }
+
+    async fn check_grpc_health(
+        &self,
+        id: ContainerId,
+        container_port: Port,
+        service: Option<&str>,
+    ) -> bool {
+        let mut exec_command = vec![
+            String::from("grpc_health_probe"),
+            format!("-addr=127.0.0.1:{container_port}"),
+        ];
+        if let Some(service) = service {
+            exec_command.push(format!("-service={service}"));
+        }
+        self.exec_succeed(id, exec_command).await
+    }
+
+    async fn check_file_exists(&self, id: ContainerId, path: &Path) -> bool {
+        let path = path.to_string_lossy();
+        let exec_command = vec![String::from("test"), String::from("-f"), path.to_string()];
+        self.exec_succeed(id, exec_command).await
+    }
+
+    /// Execute a command into the container, and check the command succeed
+    async fn exec_succeed(&self, id: ContainerId, exec_command: Vec<String>) -> bool {
+        let mut cmd = self.command();
+        cmd.push_arg("exec");
+        cmd.push_arg(id);
+        cmd.push_args(exec_command);
+        cmd.ignore_stderr();
+        cmd.status().await.is_ok()
+    }
+
+    async fn check_for_state(
+        &self,
+        id: ContainerId,
+        state: ContainerStatus,
+    ) -> Result<bool, ContainerError> {
+        let status = self.full_status(id).await?;
+        Ok(status.status == state)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, id), fields(runner = %self, id = %id))]
+    async fn rm(&self, id: ContainerId) -> Result<(), ContainerError> {
+        let mut cmd = self.command();
+        cmd.push_arg("rm");
+        cmd.push_arg(id);
+        let status = cmd.status().await?;
+
+        if status.success() {
             info!(%id, "🧹 Container removed");
             Ok(())
         } else {
@@ -477,12 +1084,118 @@ pub(crate) trait InnerRunner: Display + Debug + Send + Sync {
         }
     }
 
-    #[tracing::instrument(skip(self, image), fields(runner = %self, image = %image))]
+    #[tracing::instrument(level = "debug", skip(self, id), fields(runner = %self, id = %id))]
+    async fn wait_removed(&self, id: ContainerId, timeout: Duration) -> Result<(), ContainerError> {
+        let wait = async {
+            while self.ps_by_id(id).await?.is_some() {
+                trace!(%id, "Container still exists, will retry later");
+                tokio::time::sleep(WAIT_REMOVED_INTERVAL).await;
+            }
+            Ok::<_, ContainerError>(())
+        };
+        tokio::time::timeout(timeout, wait)
+            .await
+            .map_err(|_| ContainerError::RemoveTimeout { id, timeout })??;
+        info!(%id, "🧹 Container removal completed");
+        Ok(())
+    }
+
+    /// Stop and remove the container
+    ///
+    /// A missing container is already removed, e.g. with the `--rm` option
+    #[tracing::instrument(level = "debug", skip(self), fields(runner = %self))]
+    async fn stop_and_remove(&self, id: ContainerId) -> Result<(), ContainerError> {
+        let mut cmd = self.command();
+        cmd.push_arg("stop");
+        if let Some(timeout) = self.stop_timeout() {
+            cmd.push_arg(format!("--time={timeout}"));
+        }
+        cmd.push_arg(id);
+        match cmd.result().await {
+            Ok(_) => info!(%id, "🛑 Container stopped"),
+            Err(err) if is_missing_container(&err) => return Ok(()),
+            Err(err) => return Err(err.into()),
+        }
+
+        let mut cmd = self.command();
+        cmd.push_args(["rm", "--force", "--volumes"]);
+        cmd.push_arg(id);
+        match cmd.result().await {
+            Ok(_) => info!(%id, "🧹 Container removed"),
+            Err(err) if is_missing_container(&err) => {}
+            Err(err) => return Err(err.into()),
+        }
+        Ok(())
+    }
+
+    /// Stop and remove the container created by a start that timed out
+    #[tracing::instrument(level = "debug", skip(self), fields(runner = %self))]
+    async fn remove_created(&self, created: CreatedContainer) {
+        let id = match created {
+            CreatedContainer::None => return,
+            CreatedContainer::Pending(None) => {
+                warn!("⚠️ The unnamed container could be created, it can not be removed");
+                return;
+            }
+            CreatedContainer::Pending(Some(name)) => match self.ps(&name).await {
+                Ok(Some(process)) => process.id,
+                Ok(None) => return,
+                Err(err) => {
+                    error!(%name, "Fail to find the container because {err}");
+                    return;
+                }
+            },
+            CreatedContainer::Created(id) => id,
+        };
+        warn!(%id, "⌛ Start timeout, removing the container");
+        if let Err(err) = self.stop_and_remove(id).await {
+            error!(%id, "Fail to remove the container because {err}");
+        }
+    }
+
+    /// Reuse an existing container, it's started if needed
+    ///
+    /// Returns `None` if the container can not be reused, e.g. a dead container is removed
+    #[tracing::instrument(level = "debug", skip(self, id, networks), fields(runner = %self, id = %id))]
+    async fn reuse_container(
+        &self,
+        state: ContainerStatus,
+        id: ContainerId,
+        networks: &[Network],
+    ) -> Result<Option<ContainerId>, ContainerError> {
+        match state {
+            // Nothing to do for the container
+            state if state.is_running() => {}
+            // Need to unpause the container
+            ContainerStatus::Paused => self.unpause(id).await?,
+            // Need to start the container
+            state if state.needs_start() => self.start(id).await?,
+            // Need cleanup before recreating the container
+            ContainerStatus::Dead => {
+                self.rm(id).await?;
+                return Ok(None);
+            }
+            // Need to create and start the container, e.g. unknown or removing
+            _ => return Ok(None),
+        }
+        self.connect_missing_networks(id, networks).await?;
+        Ok(Some(id))
+    }
+
+    #[tracing::instrument(skip(self, image), fields(runner = %self, image = %image, id = tracing::field::Empty))]
     async fn start_container(
         &self,
         image: &mut RunnableContainer,
         options: RunOption,
+        created: &CreationTracker,
     ) -> Result<ContainerId, ContainerError> {
+        // Pod
+        if let Some(pod) = &options.pod {
+            if !self.supports_pod() {
+                return Err(ContainerError::PodUnsupported(pod.clone()));
+            }
+        }
+
         // Container name
         let name = options.name.as_deref();
         let container_name = image.container_name.as_deref().or(name);
@@ -492,54 +1205,76 @@ pub(crate) trait InnerRunner: Display + Debug + Send + Sync {
             None
         };
 
-        let id = match container {
-            // Nothing to do for the container
-            Some((ContainerStatus::Restarting | ContainerStatus::Running, id)) => id,
-            // Need to unpause the container
-            Some((ContainerStatus::Paused, id)) => {
-                self.unpause(id).await?;
-                id
-            }
-            // Need to start the container
-            Some((
-                ContainerStatus::Created | ContainerStatus::Exited | ContainerStatus::Stopped,
-                id,
-            )) => {
-                self.start(id).await?;
-                id
-            }
-            // Need cleanup before restarting the container
-            Some((ContainerStatus::Dead, id)) => {
-                self.rm(id).await?;
-                self.create_and_start(CreateAndStartOption::new(image, &options))
-                    .await?
-            }
-            // Need to create and start the container
-            Some((ContainerStatus::Unknown | ContainerStatus::Removing, _)) => {
-                self.create_and_start(CreateAndStartOption::new(image, &options))
-                    .await?
+        let reused = if let Some((state, id)) = container {
+            self.reuse_container(state, id, &options.additional_networks)
+                .await?
+        } else {
+            None
+        };
+
+        let id = if let Some(id) = reused {
+            id
+        } else {
+            let mut options = Cow::Borrowed(&options);
+            // If the user has specified a network or a pod, we'll assume the user knows best
+            if container.is_none()
+                & options.network.is_none()
+                & options.pod.is_none()
+                & self.get_docker_host().is_none()
+                & !self.is_rootless()
+            {
+                // Otherwise we'll try to find the docker host for dind usage.
+                let host_network = self.find_host_network().await?;
+                options.to_mut().network = host_network;
             }
-            Option::None => {
-                let mut options = Cow::Borrowed(&options);
-                // If the user has specified a network, we'll assume the user knows best
-                if options.network.is_none() & self.get_docker_host().is_none() {
-                    // Otherwise we'll try to find the docker host for dind usage.
-                    let host_network = self.find_host_network().await?;
-                    options.to_mut().network = host_network;
+            let option = CreateAndStartOption::new(image, &options);
+            created.set(CreatedContainer::Pending(
+                option.name.as_deref().map(String::from),
+            ));
+            match self.create_and_start(option).await {
+                Ok(id) => {
+                    created.set(CreatedContainer::Created(id));
+                    id
+                }
+                // Another task has created a container with the same name in the meantime
+                Err(ContainerError::CommandError(err)) if is_name_conflict(&err) => {
+                    created.set(CreatedContainer::None);
+                    let networks = &options.additional_networks;
+                    let reused = match container_name {
+                        Some(name) => match self.ps(name).await? {
+                            Some(existing) => {
+                                warn!(%name, "Container name conflict, reusing the existing container");
+                                self.reuse_container(existing.state, existing.id, networks)
+                                    .await?
+                            }
+                            None => None,
+                        },
+                        None => None,
+                    };
+                    reused.ok_or(ContainerError::CommandError(err))?
                 }
-                self.create_and_start(CreateAndStartOption::new(image, &options))
-                    .await?
+                Err(err) => return Err(err),
             }
         };
+        tracing::Span::current().record("id", tracing::field::display(id));
 
         // Wait
         // TODO maybe set a timeout
-        self.wait_ready(id, &image.wait_strategy, options.wait_interval)
+        for wait_strategy in image.wait_strategies() {
+            self.wait_ready(
+                id,
+                wait_strategy,
+                options.wait_interval,
+                options.log_buffer_size,
+            )
             .await?;
+        }
 
         // Port Mapping
         for port_mapping in &mut image.port_mappings {
-            let host_port = self.port(id, port_mapping.container_port).await?;
+            let host_port = self
+                .protocol_port(id, port_mapping.container_port, port_mapping.protocol)
+                .await?;
             port_mapping.bind_port(host_port).await;
         }
 
@@ -563,10 +1298,95 @@ pub(crate) trait InnerRunner: Display + Debug + Send + Sync {
         Ok(stdout)
     }
 
+    #[tracing::instrument(skip(self, id), fields(runner = %self, id = %id))]
+    async fn exec_output(
+        &self,
+        id: ContainerId,
+        exec_command: Vec<String>,
+    ) -> Result<ExecOutput, ContainerError> {
+        let mut cmd = self.command();
+        cmd.push_arg("exec");
+        cmd.push_arg(id);
+        cmd.push_args(exec_command);
+
+        let (exit_code, stdout, stderr) = cmd.exit_code_and_outputs().await?;
+        info!(%id, exit_code, "🐚 Executed\n{stdout}{stderr}");
+
+        Ok(ExecOutput {
+            exit_code,
+            stdout,
+            stderr,
+        })
+    }
+
+    #[tracing::instrument(skip(self, id), fields(runner = %self, id = %id))]
+    async fn read_file(&self, id: ContainerId, path: &str) -> Result<Vec<u8>, ContainerError> {
+        let mut cmd = self.command();
+        cmd.push_arg("exec");
+        cmd.push_arg(id);
+        cmd.push_args(["cat", path]);
+
+        match cmd.raw_result().await {
+            Ok(content) => {
+                info!(%id, path, size = content.len(), "📄 File read");
+                Ok(content)
+            }
+            Err(_)
+                if !self
+                    .exec_succeed(
+                        id,
+                        vec![String::from("test"), String::from("-f"), path.into()],
+                    )
+                    .await =>
+            {
+                Err(ContainerError::FileNotFound {
+                    id,
+                    path: path.to_string(),
+                })
+            }
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    #[tracing::instrument(skip(self, id, content), fields(runner = %self, id = %id))]
+    async fn write_file(
+        &self,
+        id: ContainerId,
+        path: &str,
+        content: &[u8],
+    ) -> Result<(), ContainerError> {
+        // Check the target directory, `cp` would create it as a file
+        let dir = match path.rsplit_once('/') {
+            Some(("", _)) => "/",
+            Some((dir, _)) => dir,
+            None => ".",
+        };
+        let check_dir = vec![String::from("test"), String::from("-d"), dir.into()];
+        if !self.exec_succeed(id, check_dir).await {
+            return Err(ContainerError::DirectoryNotFound {
+                id,
+                path: dir.to_string(),
+            });
+        }
+
+        let file = TemporaryContentFile::new(content)?;
+        let mut cmd = self.command();
+        cmd.push_arg("cp");
+        cmd.push_arg(file.as_ref().to_string_lossy());
+        cmd.push_arg(format!("{id}:{path}"));
+        cmd.status().await?;
+        info!(%id, path, size = content.len(), "📝 File written");
+
+        Ok(())
+    }
+
     #[tracing::instrument(skip(self, id), fields(runner = %self, id = %id))]
     fn stop(&self, id: ContainerId) -> Result<(), ContainerError> {
         let mut cmd = self.command();
         cmd.push_arg("stop");
+        if let Some(timeout) = self.stop_timeout() {
+            cmd.push_arg(format!("--time={timeout}"));
+        }
         cmd.push_arg(id);
         let status = cmd.status_blocking()?;
         if status.success() {
@@ -585,35 +1405,246 @@ fn parse_port(str: &str) -> Option<Port> {
         .next()
 }
 
+/// Map the missing manifest for the host platform error
+fn check_unsupported_architecture(image: &str, err: CommandError) -> ContainerError {
+    if let CommandError::CommandFail { output, .. } = &err {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if let Some(platform) = parse_unsupported_platform(&stderr) {
+            return ContainerError::UnsupportedArchitecture {
+                image: image.to_string(),
+                platform,
+            };
+        }
+    }
+    ContainerError::from(err)
+}
+
+/// Check if the platform architecture is the host architecture
+///
+/// The platform is `os/arch[/variant]`, e.g. `linux/arm64/v8`,
+/// the host architecture is a Rust architecture, see [`std::env::consts::ARCH`].
+fn is_native_platform(platform: &str, host_arch: &str) -> bool {
+    let mut parts = platform.split('/');
+    let first = parts.next().unwrap_or_default();
+    let arch = parts.next().unwrap_or(first);
+    let host_arch = match host_arch {
+        "x86_64" => "amd64",
+        "x86" => "386",
+        "aarch64" => "arm64",
+        "powerpc64" => "ppc64le",
+        other => other,
+    };
+    // A 64-bit x86 host runs 32-bit x86 binaries natively
+    arch == host_arch || (host_arch == "amd64" && arch == "386")
+}
+
+/// Check if the command fails because the binary format is not supported
+fn is_exec_format_error(err: &CommandError) -> bool {
+    let CommandError::CommandFail { output, .. } = err else {
+        return false;
+    };
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    has_exec_format_error(&stderr)
+}
+
+/// Detect the binary format error, i.e. a foreign architecture without emulation
+///
+/// * docker: `exec /bin/true: exec format error`
+/// * podman: `{"msg":"exec container process `/bin/true`: Exec format error","level":"error"}`
+fn has_exec_format_error(stderr: &str) -> bool {
+    stderr.to_lowercase().contains("exec format error")
+}
+
+/// Check if the `run` fails because the container name is already in use
+fn is_name_conflict(err: &CommandError) -> bool {
+    let CommandError::CommandFail { output, .. } = err else {
+        return false;
+    };
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    has_name_conflict(&stderr)
+}
+
+/// Detect the container name conflict error
+///
+/// * docker: `Conflict. The container name "/redis" is already in use by container "..."`
+/// * podman: `the container name "redis" is already in use by ...`
+/// * nerdctl: `name "redis" is already used by ID "..."`
+fn has_name_conflict(stderr: &str) -> bool {
+    stderr.contains("is already in use") || stderr.contains("is already used by")
+}
+
+/// Check if the command fails because the container does not exist, or it's being removed
+///
+/// * docker: `No such container: 2b3e`, or `removal of container 2b3e is already in progress`
+/// * podman: `no container with name or ID "2b3e" found: no such container`
+fn is_missing_container(err: &CommandError) -> bool {
+    let CommandError::CommandFail { output, .. } = err else {
+        return false;
+    };
+    let stderr = String::from_utf8_lossy(&output.stderr).to_lowercase();
+    stderr.contains("no such container") || stderr.contains("is already in progress")
+}
+
+/// Send a probe datagram, and wait for a response
+///
+/// It's best-effort: a response, or no error before the timeout, means the port is ready.
+/// A refused connection (ICMP port unreachable) is an error.
+async fn probe_udp_port(addr: SocketAddr, probe: &[u8], timeout: Duration) -> std::io::Result<()> {
+    let socket = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0)).await?;
+    socket.connect(addr).await?;
+    socket.send(probe).await?;
+    let mut buffer = [0; 512];
+    match tokio::time::timeout(timeout, socket.recv(&mut buffer)).await {
+        Ok(Err(err)) => Err(err),
+        Ok(Ok(_)) | Err(_) => Ok(()),
+    }
+}
+
+/// Check if the `image inspect` fails because the image does not exist
+fn is_missing_image(err: &CommandError) -> bool {
+    let CommandError::CommandFail { output, .. } = err else {
+        return false;
+    };
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    has_missing_image(&stderr)
+}
+
+/// Detect the missing image error
+///
+/// * docker: `Error: No such image: foo:latest`
+/// * podman: `Error: foo: image not known`
+/// * nerdctl: `no such object: "foo"`
+fn has_missing_image(stderr: &str) -> bool {
+    let stderr = stderr.to_lowercase();
+    stderr.contains("no such image")
+        || stderr.contains("image not known")
+        || stderr.contains("no such object")
+}
+
+/// Check if the `network create` fails because the network already exists
+fn is_network_already_exists(err: &CommandError) -> bool {
+    let CommandError::CommandFail { output, .. } = err else {
+        return false;
+    };
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    has_network_already_exists(&stderr)
+}
+
+/// Detect the existing network error
+///
+/// * docker: `network with name my_network already exists`
+/// * podman: `network name my_network already used: network already exists`
+/// * nerdctl: `network with name "my_network" already exists`
+fn has_network_already_exists(stderr: &str) -> bool {
+    stderr.contains("already exists")
+}
+
+/// Extract the platform from the missing manifest error
+///
+/// * docker: `no matching manifest for linux/arm64/v8 in the manifest list entries`
+/// * podman: `no image found in manifest list for architecture arm64, variant "v8", OS linux`
+fn parse_unsupported_platform(stderr: &str) -> Option<String> {
+    if let Some((_, rest)) = stderr.split_once("no matching manifest for ") {
+        let platform = rest.split_whitespace().next().unwrap_or_default();
+        return Some(platform.to_string());
+    }
+    if let Some((_, rest)) = stderr.split_once("no image found in manifest list for architecture ")
+    {
+        let platform = rest.lines().next().unwrap_or_default().trim();
+        return Some(platform.to_string());
+    }
+    None
+}
+
+/// The container created during a start, to remove it if the start times out
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) enum CreatedContainer {
+    /// No container created, e.g. an existing container is reused
+    #[default]
+    None,
+
+    /// The container is being created, with its name if any
+    Pending(Option<String>),
+
+    /// The container is created
+    Created(ContainerId),
+}
+
+/// Track the container created during a start
+#[derive(Debug, Default)]
+pub(crate) struct CreationTracker(Mutex<CreatedContainer>);
+
+impl CreationTracker {
+    fn set(&self, value: CreatedContainer) {
+        *self.0.lock().unwrap_or_else(PoisonError::into_inner) = value;
+    }
+
+    pub(crate) fn take(&self) -> CreatedContainer {
+        std::mem::take(&mut *self.0.lock().unwrap_or_else(PoisonError::into_inner))
+    }
+}
+
+#[allow(clippy::struct_excessive_bools)]
 pub(crate) struct CreateAndStartOption<'a> {
+    image: &'a ImageReference,
+    build: Option<&'a BuildContext>,
     descriptor: String,
     health_check: Option<&'a HealthCheck>,
     ports: &'a [ExposedPort],
     remove: bool,
-    name: Option<&'a str>,
+    init: bool,
+    name: Option<Cow<'a, str>>,
+    platform: Option<&'a str>,
+    check_emulation: bool,
     network: Cow<'a, Network>,
-    volumes: &'a [Volume],
+    additional_networks: &'a [Network],
+    pod: Option<&'a str>,
+    volumes: Vec<&'a Volume>,
     env: IndexMap<&'a str, &'a str>,
+    env_files: &'a [PathBuf],
+    group_add: &'a [String],
+    docker_socket: bool,
+    sysctls: &'a IndexMap<String, String>,
+    oom_kill_disable: bool,
+    oom_score_adj: Option<i32>,
+    tty: bool,
+    interactive: bool,
+    cgroup_parent: Option<&'a str>,
+    cgroupns: Option<CgroupNamespace>,
     command: &'a [String],
     entrypoint: Option<&'a str>,
 }
 
 impl<'a> CreateAndStartOption<'a> {
     pub(super) fn new<'b: 'a, 'c: 'a>(image: &'b RunnableContainer, option: &'c RunOption) -> Self {
+        let build = image.build.as_ref();
         let descriptor = image.descriptor();
-        let health_check = if let WaitStrategy::CustomHealthCheck(hc) = &image.wait_strategy {
-            Some(hc)
-        } else {
-            None
-        };
+        // A container has at most one health check
+        let health_check = image.wait_strategies().iter().find_map(|strategy| {
+            if let WaitStrategy::CustomHealthCheck(hc) = strategy {
+                Some(hc)
+            } else {
+                None
+            }
+        });
         let ports = &image.port_mappings;
         let remove = option.remove;
-        let name = option.name();
+        let init = option.init;
+        let name = option.name().map(Cow::Borrowed).or_else(|| {
+            option.name_prefix.as_deref().map(|prefix| {
+                let suffix = Ulid::new().to_string().to_lowercase();
+                Cow::Owned(format!("{prefix}-{suffix}"))
+            })
+        });
+        let platform = option.platform.as_deref();
+        let check_emulation = option.check_emulation;
         let network = option
             .network
             .as_ref()
             .map_or_else(|| Cow::Owned(Network::default()), Cow::Borrowed);
-        let volumes = option.volumes.as_slice();
+        let additional_networks = option.additional_networks.as_slice();
+        let pod = option.pod.as_deref();
+        let volumes = image.volumes.iter().chain(&option.volumes).collect();
         let env = image
             .env
             .iter()
@@ -625,6 +1656,16 @@ impl<'a> CreateAndStartOption<'a> {
                     .map(|(key, value)| (key.as_str(), value.as_str())),
             )
             .collect();
+        let env_files = option.env_files.as_slice();
+        let group_add = option.group_add.as_slice();
+        let docker_socket = option.docker_socket;
+        let sysctls = &option.sysctls;
+        let oom_kill_disable = option.oom_kill_disable;
+        let oom_score_adj = option.oom_score_adj;
+        let tty = option.tty;
+        let interactive = option.interactive;
+        let cgroup_parent = option.cgroup_parent.as_deref();
+        let cgroupns = option.cgroupns;
         let command = if let Some(cmd) = &option.command {
             cmd.as_slice()
         } else {
@@ -633,18 +1674,175 @@ impl<'a> CreateAndStartOption<'a> {
         let entrypoint = option.entrypoint.as_deref();
 
         Self {
+            image: &image.image,
+            build,
             descriptor,
             health_check,
             ports,
             remove,
+            init,
             name,
+            platform,
+            check_emulation,
             network,
+            additional_networks,
+            pod,
             volumes,
             env,
+            env_files,
+            group_add,
+            docker_socket,
+            sysctls,
+            oom_kill_disable,
+            oom_score_adj,
+            tty,
+            interactive,
+            cgroup_parent,
+            cgroupns,
             command,
             entrypoint,
         }
     }
+
+    /// Check the options are compatible, before launching the container
+    ///
+    /// The runner rejects (or silently ignores) some combinations, with a cryptic message.
+    pub(super) fn validate(&self) -> Result<(), ContainerError> {
+        let reason = if self.pod.is_some() {
+            (!self.ports.is_empty()).then_some(
+                "the ports cannot be published with a pod, they should be published when the pod is created",
+            )
+        } else {
+            match self.network.as_ref() {
+                Network::Host if !self.ports.is_empty() => Some(
+                    "the ports cannot be published with the host network, the container ports are directly bound on the host",
+                ),
+                Network::Container(_) if !self.ports.is_empty() => Some(
+                    "the ports cannot be published with a container network, they should be published by the network container",
+                ),
+                Network::Host | Network::None | Network::Container(_)
+                    if !self.additional_networks.is_empty() =>
+                {
+                    Some("the additional networks require a bridge, or a custom network")
+                }
+                _ => None,
+            }
+        };
+        match reason {
+            Some(reason) => Err(ContainerError::IncompatibleOptions {
+                reason: reason.to_string(),
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Push the `run` command arguments, i.e. the options, the image, and the command
+    async fn push_run_args(&self, cmd: &mut Cmd<'_>) -> Result<(), ContainerError> {
+        // Remove
+        if self.remove {
+            cmd.push_arg("--rm");
+        }
+
+        // Init
+        if self.init {
+            cmd.push_arg("--init");
+        }
+
+        // Name
+        if let Some(name) = &self.name {
+            cmd.push_args(["--name", name]);
+        }
+
+        // Platform
+        if let Some(platform) = self.platform {
+            cmd.push_args(["--platform", platform]);
+        }
+
+        // Env. files, the env. vars. take precedence
+        for env_file in self.env_files {
+            cmd.push_arg("--env-file");
+            cmd.push_arg(env_file.display().to_string());
+        }
+
+        // Env. vars.
+        for (key, value) in &self.env {
+            let env_var = format!("{key}={value}");
+            cmd.push_args(["--env", &env_var]);
+        }
+
+        // Supplementary groups
+        for group in self.group_add {
+            cmd.push_args(["--group-add", group]);
+        }
+
+        // Kernel parameters
+        for (key, value) in self.sysctls {
+            let sysctl = format!("{key}={value}");
+            cmd.push_args(["--sysctl", &sysctl]);
+        }
+
+        // OOM killer
+        if self.oom_kill_disable {
+            cmd.push_arg("--oom-kill-disable");
+        }
+        if let Some(oom_score_adj) = self.oom_score_adj {
+            cmd.push_arg(format!("--oom-score-adj={oom_score_adj}"));
+        }
+
+        // TTY & standard input
+        if self.tty {
+            cmd.push_arg("--tty");
+        }
+        if self.interactive {
+            cmd.push_arg("--interactive");
+        }
+
+        // Cgroup
+        if let Some(cgroup_parent) = self.cgroup_parent {
+            cmd.push_arg(format!("--cgroup-parent={cgroup_parent}"));
+        }
+        if let Some(cgroupns) = self.cgroupns {
+            cmd.push_arg(format!("--cgroupns={cgroupns}"));
+        }
+
+        // Published ports
+        for port_mapping in self.ports {
+            let publish = port_mapping.to_publish().await;
+            cmd.push_args(["--publish", &publish]);
+        }
+
+        // Health check args.
+        if let Some(hc) = &self.health_check {
+            cmd.push_args(hc.to_vec());
+        }
+
+        // Network, or pod (the pod owns the network)
+        if let Some(pod) = self.pod {
+            cmd.push_args(["--pod", pod]);
+        } else {
+            let network = self.network.cmd_arg();
+            cmd.push_arg(network.as_ref());
+        }
+
+        // Volumes
+        for volume in &self.volumes {
+            cmd.push_arg("--mount");
+            cmd.push_arg(&volume.mount_arg()?);
+        }
+
+        // Entrypoint
+        if let Some(entrypoint) = self.entrypoint {
+            cmd.push_args(["--entrypoint", entrypoint]);
+        }
+
+        // Descriptor (name:tag or other alternatives)
+        cmd.push_arg(&self.descriptor);
+
+        // Command
+        cmd.push_args(self.command);
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -668,4 +1866,168 @@ mod tests {
         let_assert!(Some(port) = result);
         check!(port == expected);
     }
+
+    #[rstest]
+    #[case::docker(
+        "docker: no matching manifest for linux/arm64/v8 in the manifest list entries.\nSee 'docker run --help'.",
+        Some("linux/arm64/v8")
+    )]
+    #[case::podman(
+        "Error: choosing an image from manifest list docker.io/library/foo:latest: no image found in manifest list for architecture arm64, variant \"v8\", OS linux\n",
+        Some("arm64, variant \"v8\", OS linux")
+    )]
+    #[case::other("Error: pull access denied for foo", None)]
+    fn should_parse_unsupported_platform(#[case] stderr: &str, #[case] expected: Option<&str>) {
+        let result = parse_unsupported_platform(stderr);
+        check!(result.as_deref() == expected);
+    }
+
+    #[rstest]
+    #[case::docker(
+        "docker: Error response from daemon: Conflict. The container name \"/redis\" is already in use by container \"2b3e\". You have to remove (or rename) that container to be able to reuse that name.",
+        true
+    )]
+    #[case::podman(
+        "Error: creating container storage: the container name \"redis\" is already in use by 2b3e. You have to remove that container to be able to reuse that name: that name is already in use",
+        true
+    )]
+    #[case::nerdctl("FATA[0000] name \"redis\" is already used by ID \"2b3e\"", true)]
+    #[case::other("Error: no such image", false)]
+    fn should_detect_name_conflict(#[case] stderr: &str, #[case] expected: bool) {
+        check!(has_name_conflict(stderr) == expected);
+    }
+
+    #[rstest]
+    #[case::docker(
+        "Error response from daemon: network with name my_network already exists",
+        true
+    )]
+    #[case::podman(
+        "Error: network name my_network already used: network already exists",
+        true
+    )]
+    #[case::nerdctl("FATA[0000] network with name \"my_network\" already exists", true)]
+    #[case::other("Error response from daemon: invalid network name", false)]
+    fn should_detect_network_already_exists(#[case] stderr: &str, #[case] expected: bool) {
+        check!(has_network_already_exists(stderr) == expected);
+    }
+
+    #[rstest]
+    #[case::docker("Error response from daemon: No such image: foo:latest", true)]
+    #[case::podman("Error: foo: image not known", true)]
+    #[case::nerdctl("FATA[0000] 1 errors:\nno such object: \"foo\"", true)]
+    #[case::other("Cannot connect to the Docker daemon", false)]
+    fn should_detect_missing_image(#[case] stderr: &str, #[case] expected: bool) {
+        check!(has_missing_image(stderr) == expected);
+    }
+
+    #[tokio::test]
+    async fn should_probe_udp_port() {
+        let server = UdpSocket::bind((Ipv4Addr::LOCALHOST, 0))
+            .await
+            .expect("bind server");
+        let addr = server.local_addr().expect("server address");
+        tokio::spawn(async move {
+            let mut buffer = [0; 16];
+            if let Ok((_, peer)) = server.recv_from(&mut buffer).await {
+                let _ = server.send_to(b"pong", peer).await;
+            }
+        });
+
+        let result = probe_udp_port(addr, b"ping", Duration::from_secs(1)).await;
+        let_assert!(Ok(()) = result);
+    }
+
+    #[tokio::test]
+    async fn should_fail_to_probe_closed_udp_port() {
+        let addr = {
+            let socket = UdpSocket::bind((Ipv4Addr::LOCALHOST, 0))
+                .await
+                .expect("bind");
+            socket.local_addr().expect("address")
+        };
+
+        let result = probe_udp_port(addr, b"ping", Duration::from_secs(1)).await;
+        check!(result.is_err());
+    }
+
+    fn container_network() -> Network {
+        let id = "8b9c0f3a4e1d2c5b6a7f8e9d0c1b2a3f4e5d6c7b8a9f0e1d2c3b4a5f6e7d8c9b"
+            .parse::<ContainerId>()
+            .expect("container id");
+        Network::Container(id)
+    }
+
+    #[rstest]
+    #[case::bridge(RunOption::default(), true, true)]
+    #[case::custom(
+        RunOption::builder().with_network(Network::Custom("my-network".into())).with_additional_networks([Network::Custom("other".into())]).build(),
+        true,
+        true
+    )]
+    #[case::host(RunOption::builder().with_network(Network::Host).build(), false, true)]
+    #[case::host_without_ports(RunOption::builder().with_network(Network::Host).build(), true, false)]
+    #[case::container(RunOption::builder().with_network(container_network()).build(), false, true)]
+    #[case::container_without_ports(RunOption::builder().with_network(container_network()).build(), true, false)]
+    #[case::pod(RunOption::builder().with_pod("my-pod").build(), false, true)]
+    #[case::pod_without_ports(RunOption::builder().with_pod("my-pod").build(), true, false)]
+    #[case::host_additional_networks(
+        RunOption::builder().with_network(Network::Host).with_additional_networks([Network::Custom("other".into())]).build(),
+        false,
+        false
+    )]
+    #[case::none_additional_networks(
+        RunOption::builder().with_network(Network::None).with_additional_networks([Network::Custom("other".into())]).build(),
+        false,
+        false
+    )]
+    fn should_validate_options(
+        #[case] options: RunOption,
+        #[case] expected: bool,
+        #[case] with_ports: bool,
+    ) {
+        let builder = RunnableContainer::builder().with_image(ImageName::new("nginx"));
+        let image = if with_ports {
+            builder.with_port_mappings([ExposedPort::new(80)]).build()
+        } else {
+            builder.build()
+        };
+        let option = CreateAndStartOption::new(&image, &options);
+
+        let result = option.validate();
+        if expected {
+            let_assert!(Ok(()) = result);
+        } else {
+            let_assert!(Err(ContainerError::IncompatibleOptions { reason }) = result);
+            check!(!reason.is_empty());
+        }
+    }
+
+    #[rstest]
+    #[case::amd64("linux/amd64", "x86_64", true)]
+    #[case::i386("linux/386", "x86_64", true)]
+    #[case::arm64("linux/arm64", "x86_64", false)]
+    #[case::arm64_variant("linux/arm64/v8", "aarch64", true)]
+    #[case::arm_v7("linux/arm/v7", "aarch64", false)]
+    #[case::amd64_on_arm("linux/amd64", "aarch64", false)]
+    #[case::ppc64le("linux/ppc64le", "powerpc64", true)]
+    #[case::arch_only("s390x", "s390x", true)]
+    fn should_detect_native_platform(
+        #[case] platform: &str,
+        #[case] host_arch: &str,
+        #[case] expected: bool,
+    ) {
+        check!(is_native_platform(platform, host_arch) == expected);
+    }
+
+    #[rstest]
+    #[case::docker("exec /bin/true: exec format error", true)]
+    #[case::podman(
+        "{\"msg\":\"exec container process `/bin/true`: Exec format error\",\"level\":\"error\"}",
+        true
+    )]
+    #[case::other("Unable to find image 'alpine:latest' locally", false)]
+    fn should_detect_exec_format_error(#[case] stderr: &str, #[case] expected: bool) {
+        check!(has_exec_format_error(stderr) == expected);
+    }
 }
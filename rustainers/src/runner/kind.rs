@@ -0,0 +1,87 @@
+use super::{Runner, RunnerError};
+
+/// The environment variable used to define the runners order, see [`Runner::auto`]
+///
+/// It contains a comma separated list of runner kinds, e.g. `podman,docker`.
+pub const RUNNER_ENV_VAR: &str = "RUSTAINERS_RUNNER";
+
+/// The kind of runner
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, strum_macros::Display, strum_macros::EnumString,
+)]
+#[strum(serialize_all = "lowercase", ascii_case_insensitive)]
+#[non_exhaustive]
+pub enum RunnerKind {
+    /// Docker
+    Docker,
+
+    /// Podman
+    Podman,
+
+    /// Nerdctl
+    Nerdctl,
+}
+
+impl RunnerKind {
+    /// The default order used to find an available runner
+    pub const DEFAULT_ORDER: &'static [Self] = &[Self::Docker, Self::Podman, Self::Nerdctl];
+
+    /// Create the runner
+    ///
+    /// # Errors
+    ///
+    /// Fail if the runner command is not found
+    /// Fail if the runner command version is unsupported
+    pub fn create(self) -> Result<Runner, RunnerError> {
+        match self {
+            Self::Docker => Runner::docker(),
+            Self::Podman => Runner::podman(),
+            Self::Nerdctl => Runner::nerdctl(),
+        }
+    }
+}
+
+impl Runner {
+    /// The runner kind
+    #[must_use]
+    pub fn kind(&self) -> RunnerKind {
+        match self {
+            Self::Docker(_) => RunnerKind::Docker,
+            Self::Podman(_) => RunnerKind::Podman,
+            Self::Nerdctl(_) => RunnerKind::Nerdctl,
+        }
+    }
+}
+
+/// Parse a comma separated list of runner kinds
+pub(super) fn parse_runner_order(str: &str) -> Result<Vec<RunnerKind>, strum::ParseError> {
+    str.split(',')
+        .map(str::trim)
+        .filter(|kind| !kind.is_empty())
+        .map(str::parse)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::single("podman", &[RunnerKind::Podman])]
+    #[case::multiple("podman, Docker", &[RunnerKind::Podman, RunnerKind::Docker])]
+    #[case::empty("", &[])]
+    fn should_parse_runner_order(#[case] str: &str, #[case] expected: &[RunnerKind]) {
+        let result = parse_runner_order(str);
+        let_assert!(Ok(order) = result);
+        check!(order == expected);
+    }
+
+    #[test]
+    fn should_not_parse_invalid_runner_order() {
+        let result = parse_runner_order("docker,plop");
+        check!(result.is_err());
+    }
+}
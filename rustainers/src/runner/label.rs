@@ -0,0 +1,74 @@
+use indexmap::IndexMap;
+
+use crate::cmd::Cmd;
+
+/// A label filter, used to list the containers, networks, or volumes
+///
+/// All the labels should match, the default filter matches everything.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// # async fn run() -> anyhow::Result<()> {
+/// use rustainers::runner::{LabelFilter, Runner};
+///
+/// let filter = LabelFilter::default()
+///     .with_label_filter("com.example.suite", "integration")
+///     .with_label_key("com.example.owner");
+///
+/// # let runner = Runner::auto()?;
+/// let containers = runner.list_running_containers(&filter).await?;
+/// let networks = runner.list_networks(&filter).await?;
+/// let volumes = runner.list_volumes(&filter).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LabelFilter {
+    labels: IndexMap<String, Option<String>>,
+}
+
+impl LabelFilter {
+    /// Only match resources with the label `{key}={value}`
+    #[must_use]
+    pub fn with_label_filter(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.labels.insert(key.into(), Some(value.into()));
+        self
+    }
+
+    /// Only match resources having the label `{key}`, whatever the value
+    #[must_use]
+    pub fn with_label_key(mut self, key: impl Into<String>) -> Self {
+        self.labels.insert(key.into(), None);
+        self
+    }
+
+    /// Push the `--filter label=...` arguments
+    pub(crate) fn push_args(&self, cmd: &mut Cmd<'_>) {
+        for (key, value) in &self.labels {
+            let filter = if let Some(value) = value {
+                format!("label={key}={value}")
+            } else {
+                format!("label={key}")
+            };
+            cmd.push_args(["--filter", &filter]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::check;
+
+    use super::*;
+
+    #[test]
+    fn should_push_label_filters() {
+        let filter = LabelFilter::default()
+            .with_label_filter("suite", "it")
+            .with_label_key("owner");
+        let mut cmd = Cmd::new("docker");
+        filter.push_args(&mut cmd);
+        check!(cmd.to_string() == "docker --filter label=suite=it --filter label=owner");
+    }
+}
@@ -1,11 +1,30 @@
+use std::env;
 use std::fmt::{self, Debug, Display};
 use std::net::IpAddr;
+use std::path::PathBuf;
 use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime};
 
-use tracing::info;
+use tokio::sync::mpsc;
+use tracing::{debug, info, warn};
 
-use crate::{Container, Network, RunnableContainer, ToRunnableContainer, VolumeName};
+use crate::images::GenericImage;
+use crate::io::DEFAULT_LOG_BUFFER_SIZE;
+use crate::{
+    BuildImage, Container, ContainerConfig, ContainerGuard, ContainerId, ContainerProcess,
+    ContainerStats, ExecOutput, HealthLogEntry, ImageInspect, ImageName, ImageReference, LogsSince,
+    Network, RunnableContainer, ToRunnableContainer, VolumeName,
+};
+
+/// The docker configuration directory environment variable
+pub(crate) const DOCKER_CONFIG_ENV: &str = "DOCKER_CONFIG";
+
+/// The capacity of the channel used to stream the container stats
+const STATS_BUFFER_SIZE: usize = 16;
+
+mod build;
+pub use self::build::BuildStream;
 
 mod docker;
 pub use self::docker::Docker;
@@ -14,17 +33,39 @@ mod nerdctl;
 pub use self::nerdctl::Nerdctl;
 
 mod podman;
-pub use self::podman::Podman;
+pub use self::podman::{Podman, PodmanComposeCommand};
 
 mod error;
 pub use self::error::*;
 
+mod feature;
+pub use self::feature::Feature;
+use self::feature::{
+    DOCKER_GPUS_MINIMAL_VERSION, DOCKER_HEALTH_START_INTERVAL_MINIMAL_VERSION,
+    PODMAN_GPUS_MINIMAL_VERSION,
+};
+
+mod kind;
+pub use self::kind::{RunnerKind, RUNNER_ENV_VAR};
+
 mod inner;
 pub(crate) use self::inner::*;
 
+mod label;
+pub use self::label::LabelFilter;
+
 mod options;
 pub use self::options::*;
 
+mod probe;
+use self::probe::probe_version;
+
+mod config;
+pub(crate) use self::config::{RunnerConfig, RunnerInstance};
+
+mod registry;
+use self::registry::CONTAINER_REGISTRY;
+
 /// The test containers runner
 ///
 /// Use the [`Runner::auto`], [`Runner::docker`], [`Runner::podman`], [`Runner::nerdctl`] functions
@@ -56,31 +97,82 @@ impl Display for Runner {
 impl Runner {
     /// Find an available runner
     ///
+    /// The runners are checked in this order: docker, podman, nerdctl.
+    /// The order could be changed with the [`RUNNER_ENV_VAR`] environment variable,
+    /// e.g. `RUSTAINERS_RUNNER=podman,docker`.
+    /// See [`Runner::auto_with_report`] to know why runners are rejected.
+    ///
     /// # Errors
     ///
     /// Fail if no runner is available
     ///
     pub fn auto() -> Result<Self, RunnerError> {
-        // Check Docker
-        if let Ok(runner) = Self::docker() {
-            info!("🐳 Using docker");
-            return Ok(runner);
-        }
+        Self::auto_with_report().map_err(|report| {
+            warn!("{report}");
+            RunnerError::NoRunnerAvailable
+        })
+    }
 
-        // Check Podman
-        if let Ok(runner) = Self::podman() {
-            info!("Using podman");
-            return Ok(runner);
-        }
+    /// Find an available runner, and report why the runners are rejected
+    ///
+    /// See [`Runner::auto`] for the runners order.
+    ///
+    /// # Errors
+    ///
+    /// Fail if no runner is available, the report contains the rejection reason of each runner
+    pub fn auto_with_report() -> Result<Self, RunnerReport> {
+        let order = match env::var(RUNNER_ENV_VAR) {
+            Ok(value) => match kind::parse_runner_order(&value) {
+                Ok(order) if !order.is_empty() => order,
+                Ok(_) => RunnerKind::DEFAULT_ORDER.to_vec(),
+                Err(err) => {
+                    warn!("Invalid {RUNNER_ENV_VAR} value '{value}' because {err}, using the default order");
+                    RunnerKind::DEFAULT_ORDER.to_vec()
+                }
+            },
+            Err(_) => RunnerKind::DEFAULT_ORDER.to_vec(),
+        };
+        Self::auto_prefer_with_report(&order)
+    }
+
+    /// Find an available runner, in the provided order
+    ///
+    /// Only the provided runners are checked, so it could be used to pin a runner.
+    ///
+    /// # Errors
+    ///
+    /// Fail if no runner is available
+    pub fn auto_prefer(order: &[RunnerKind]) -> Result<Self, RunnerError> {
+        Self::auto_prefer_with_report(order).map_err(|report| {
+            warn!("{report}");
+            RunnerError::NoRunnerAvailable
+        })
+    }
 
-        // Check nerdctl
-        if let Ok(runner) = Self::nerdctl() {
-            info!("Using nerdctl");
-            return Ok(runner);
+    /// Find an available runner in the provided order, and report why the runners are rejected
+    ///
+    /// # Errors
+    ///
+    /// Fail if no runner is available, the report contains the rejection reason of each runner
+    pub fn auto_prefer_with_report(order: &[RunnerKind]) -> Result<Self, RunnerReport> {
+        let mut rejections = vec![];
+        for kind in order {
+            match kind.create() {
+                Ok(runner) => {
+                    info!("Using {runner}");
+                    return Ok(runner);
+                }
+                Err(reason) => {
+                    debug!("Runner {kind} rejected because {reason}");
+                    rejections.push(RunnerRejection {
+                        command: kind.to_string(),
+                        reason,
+                    });
+                }
+            }
         }
 
-        // Fallback
-        Err(RunnerError::NoRunnerAvailable)
+        Err(RunnerReport { rejections })
     }
 
     /// Create a docker runner
@@ -115,9 +207,162 @@ impl Runner {
         let runner = nerdctl::create()?;
         Ok(Self::Nerdctl(runner))
     }
+
+    /// Create a nerdctl runner using a containerd namespace
+    ///
+    /// The `CONTAINERD_NAMESPACE` environment variable is set for all spawned commands,
+    /// so the containers, networks, volumes, and images are isolated into this namespace.
+    ///
+    /// # Errors
+    ///
+    /// Fail if the nerdctl command is not found
+    /// Fail if the nerdctl command version is unsupported
+    pub fn nerdctl_in_namespace(namespace: impl Into<String>) -> Result<Self, RunnerError> {
+        let runner = Self::Nerdctl(nerdctl::create()?);
+        let namespace = Some(namespace.into());
+        Ok(runner.with_config(|config| config.namespace = namespace))
+    }
 }
 
 impl Runner {
+    /// The runner instance, shared by the runner clones
+    pub(crate) fn instance(&self) -> &RunnerInstance {
+        match self {
+            Self::Docker(runner) => &runner.config.instance,
+            Self::Podman(runner) => &runner.config.instance,
+            Self::Nerdctl(runner) => &runner.config.instance,
+        }
+    }
+
+    /// Update the runner settings, the clones of this runner are not changed
+    fn with_config(mut self, update: impl FnOnce(&mut RunnerConfig)) -> Self {
+        let config = match &mut self {
+            Self::Docker(runner) => &mut runner.config,
+            Self::Podman(runner) => &mut runner.config,
+            Self::Nerdctl(runner) => &mut runner.config,
+        };
+        update(Arc::make_mut(config));
+        self
+    }
+
+    /// Set the default stop timeout
+    ///
+    /// This timeout is used when a container is stopped, e.g. during the [`Container`] drop.
+    /// The runner waits this timeout before killing the container.
+    /// Note that the timeout is truncated to seconds.
+    #[must_use]
+    pub fn with_default_stop_timeout(self, timeout: Duration) -> Self {
+        let timeout = u16::try_from(timeout.as_secs()).unwrap_or(u16::MAX);
+        self.with_config(|config| config.stop_timeout = Some(timeout))
+    }
+
+    /// Set the host address used to reach the containers
+    ///
+    /// By default, the host address is `127.0.0.1`,
+    /// or the default gateway when running inside a container.
+    /// This override is useful when the detection does not work,
+    /// e.g. with Docker Desktop with Windows containers.
+    ///
+    /// See [`Runner::container_host_ip`]
+    #[must_use]
+    pub fn with_host_address(self, address: IpAddr) -> Self {
+        self.with_config(|config| config.host_address = Some(address))
+    }
+
+    /// Set the docker configuration directory
+    ///
+    /// The directory should contain a `config.json` file, e.g. with private registry credentials,
+    /// so the images are pulled without an interactive `login`.
+    ///
+    /// The `DOCKER_CONFIG` environment variable is set for all spawned commands,
+    /// for podman the `REGISTRY_AUTH_FILE` environment variable points to the `config.json` file.
+    ///
+    /// Note that this configuration takes precedence over the default configuration
+    /// (e.g. `~/.docker/config.json`), so the credentials from a previous `docker login`
+    /// are ignored, and an explicit `login` made by a command of this runner
+    /// writes the credentials into this configuration.
+    #[must_use]
+    pub fn with_docker_config(self, path: impl Into<PathBuf>) -> Self {
+        let config_dir = Some(path.into());
+        self.with_config(|config| config.config_dir = config_dir)
+    }
+
+    /// Use an explicit compose binary, e.g. `docker-compose`, or a custom wrapper
+    ///
+    /// By default, docker uses `docker compose`,
+    /// and podman uses `podman compose`, or `podman-compose`.
+    /// The binary version is checked like the built-in detection,
+    /// i.e. `<binary> version --format json`.
+    ///
+    /// ```rust, no_run
+    /// # fn run() -> anyhow::Result<()> {
+    /// use rustainers::runner::Runner;
+    ///
+    /// let runner = Runner::docker()?.with_compose_binary("docker-compose")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Fail if the binary is not found, or if the version is unsupported
+    /// Fail with nerdctl, the compose command is built-in
+    pub fn with_compose_binary(self, binary: impl Into<String>) -> Result<Self, RunnerError> {
+        let binary = binary.into();
+        let runner = match self {
+            Self::Docker(runner) => {
+                let compose_version = Some(docker::compose_binary_version(&binary)?);
+                Self::Docker(Docker {
+                    compose_version,
+                    ..runner
+                })
+            }
+            Self::Podman(runner) => {
+                let compose_version = Some(podman::compose_binary_version(&binary)?);
+                Self::Podman(Podman {
+                    compose_version,
+                    ..runner
+                })
+            }
+            Self::Nerdctl(_) => return Err(RunnerError::ComposeBinaryUnsupported(Box::new(self))),
+        };
+        Ok(runner.with_config(|config| config.compose_binary = Some(binary)))
+    }
+
+    /// Check if the runner supports a feature
+    ///
+    /// The support is based on the runner kind, and on the versions detected
+    /// when the runner is created.
+    ///
+    /// ```rust, no_run
+    /// # fn run() -> anyhow::Result<()> {
+    /// use rustainers::runner::{Feature, Runner};
+    ///
+    /// let runner = Runner::auto()?;
+    /// if runner.supports(Feature::Compose) {
+    ///     // Start compose containers
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn supports(&self, feature: Feature) -> bool {
+        match (self, feature) {
+            (Self::Docker(runner), Feature::Compose) => runner.compose_version.is_some(),
+            (Self::Docker(runner), Feature::Gpus) => runner.version >= DOCKER_GPUS_MINIMAL_VERSION,
+            (Self::Docker(runner), Feature::HealthStartInterval) => {
+                runner.version >= DOCKER_HEALTH_START_INTERVAL_MINIMAL_VERSION
+            }
+            (Self::Podman(runner), Feature::Compose) => runner.compose_version.is_some(),
+            (Self::Podman(runner), Feature::Gpus) => runner.version >= PODMAN_GPUS_MINIMAL_VERSION,
+            (Self::Podman(_), Feature::Pod)
+            | (Self::Nerdctl(_), Feature::Compose | Feature::Gpus) => true,
+            (Self::Docker(_), Feature::Pod)
+            | (Self::Podman(_), Feature::HealthStartInterval)
+            | (Self::Nerdctl(_), Feature::Pod | Feature::HealthStartInterval) => false,
+        }
+    }
+
     /// Start a runnable container
     ///
     /// The default [`RunOption`] is used
@@ -133,11 +378,27 @@ impl Runner {
         self.start_with_options(image, options).await
     }
 
+    /// Start a runnable container, with an explicit async cleanup
+    ///
+    /// The default [`RunOption`] is used, see [`ContainerGuard`]
+    ///
+    /// # Errors
+    ///
+    /// Fail if we cannot launch the container
+    pub async fn start_scoped<I>(&self, image: I) -> Result<ContainerGuard<I>, RunnerError>
+    where
+        I: ToRunnableContainer,
+    {
+        let container = self.start(image).await?;
+        Ok(ContainerGuard::from(container))
+    }
+
     /// Start a runnable container with option
     ///
     /// # Errors
     ///
     /// Fail if we cannot launch the container
+    #[tracing::instrument(skip(self, image, options), fields(runner = %self, id = tracing::field::Empty))]
     pub async fn start_with_options<I>(
         &self,
         image: I,
@@ -148,29 +409,164 @@ impl Runner {
     {
         let mut container = image.to_runnable(RunnableContainer::builder());
         let image_ref = container.image.clone();
+        // Keep the volumes alive (e.g. temporary files) with the container
+        let option_volumes = options.volumes.clone();
+        let on_started = options.on_started.clone();
+        let keep_on_failure = options.keep_on_failure;
+
+        let start_timeout = options.start_timeout;
 
-        let id = match self {
-            Self::Docker(runner) => runner.start_container(&mut container, options).await,
-            Self::Podman(runner) => runner.start_container(&mut container, options).await,
-            Self::Nerdctl(runner) => runner.start_container(&mut container, options).await,
+        let start = Instant::now();
+        let created = CreationTracker::default();
+        let start_container = async {
+            match self {
+                Self::Docker(runner) => {
+                    runner
+                        .start_container(&mut container, options, &created)
+                        .await
+                }
+                Self::Podman(runner) => {
+                    runner
+                        .start_container(&mut container, options, &created)
+                        .await
+                }
+                Self::Nerdctl(runner) => {
+                    runner
+                        .start_container(&mut container, options, &created)
+                        .await
+                }
+            }
+        };
+        let result = if let Some(timeout) = start_timeout {
+            if let Ok(result) = tokio::time::timeout(timeout, start_container).await {
+                result
+            } else {
+                // The start future is dropped, so the in-flight commands are killed
+                let created = created.take();
+                match self {
+                    Self::Docker(runner) => runner.remove_created(created).await,
+                    Self::Podman(runner) => runner.remove_created(created).await,
+                    Self::Nerdctl(runner) => runner.remove_created(created).await,
+                }
+                Err(ContainerError::StartTimeout(timeout))
+            }
+        } else {
+            start_container.await
+        };
+        let id = match result {
+            Ok(id) => id,
+            Err(source) => {
+                return Err(RunnerError::StartError {
+                    runner: self.clone(),
+                    container: Box::new(container),
+                    source: Box::new(source),
+                })
+            }
+        };
+        let startup_duration = start.elapsed();
+        tracing::Span::current().record("id", tracing::field::display(id));
+        info!(%id, ?startup_duration, "⏱️ Container ready");
+        if let Some(callback) = &on_started {
+            callback.call(&id);
         }
-        .map_err(|source| RunnerError::StartError {
+
+        let detached = Arc::new(AtomicBool::new(false));
+        let stopped = Arc::new(AtomicBool::new(false));
+        CONTAINER_REGISTRY.register(self, id, &detached, &stopped);
+
+        Ok(Container {
+            runner: self.clone(),
+            image,
+            image_ref,
+            port_mappings: container.port_mappings,
+            _volumes: container
+                .volumes
+                .into_iter()
+                .chain(option_volumes)
+                .collect(),
+            id,
+            startup_duration,
+            detached,
+            keep_on_failure,
+            stopped,
+        })
+    }
+
+    /// Run a container once, and wait until it exits
+    ///
+    /// The container is not detached, and it's removed after the exit,
+    /// the wait strategy of the image is not used.
+    ///
+    /// Returns the exit code and the standard output.
+    ///
+    /// # Errors
+    ///
+    /// Fail if we cannot launch the container
+    pub async fn run_once<I>(&self, image: I) -> Result<(i32, String), RunnerError>
+    where
+        I: ToRunnableContainer,
+    {
+        let container = image.to_runnable(RunnableContainer::builder());
+        let options = RunOption::builder().with_remove(true).build();
+        let option = CreateAndStartOption::new(&container, &options);
+
+        let result = match self {
+            Self::Docker(runner) => runner.run_once(option).await,
+            Self::Podman(runner) => runner.run_once(option).await,
+            Self::Nerdctl(runner) => runner.run_once(option).await,
+        };
+        result.map_err(|source| RunnerError::StartError {
             runner: self.clone(),
             container: Box::new(container),
             source: Box::new(source),
+        })
+    }
+
+    /// Attach an already running container, e.g. started manually, or by an external compose file
+    ///
+    /// Nothing is started, the container is found by name or id.
+    /// The returned container is detached, so it's not stopped during the drop.
+    /// The published ports are available as port mappings, see [`Container::host_port`].
+    ///
+    /// # Errors
+    ///
+    /// Fail with [`ContainerError::ContainerNotFound`] if the container does not exist,
+    /// or with [`ContainerError::ContainerNotRunning`] if it's not running.
+    /// Could fail if we cannot execute the inspect command
+    pub async fn attach_existing(
+        &self,
+        name_or_id: &str,
+    ) -> Result<Container<GenericImage>, RunnerError> {
+        let result = match self {
+            Self::Docker(runner) => runner.attach_existing(name_or_id).await,
+            Self::Podman(runner) => runner.attach_existing(name_or_id).await,
+            Self::Nerdctl(runner) => runner.attach_existing(name_or_id).await,
+        };
+        let (id, image_ref, port_mappings) = result.map_err(|source| RunnerError::AttachError {
+            runner: self.clone(),
+            name: name_or_id.to_string(),
+            source: Box::new(source),
         })?;
+        info!(%id, "🔗 Container attached");
 
         Ok(Container {
             runner: self.clone(),
-            image,
+            image: GenericImage::new(image_ref.clone()),
             image_ref,
+            port_mappings,
+            _volumes: vec![],
             id,
-            detached: Arc::new(AtomicBool::new(false)),
+            startup_duration: Duration::ZERO,
+            detached: Arc::new(AtomicBool::new(true)),
+            keep_on_failure: false,
+            stopped: Arc::new(AtomicBool::new(false)),
         })
     }
 
     /// Create a network
     ///
+    /// The creation is idempotent, if the network already exists, it's reused.
+    ///
     /// # Errors
     ///
     /// Could fail if we cannot execute the command
@@ -190,6 +586,30 @@ impl Runner {
         Ok(Network::Custom(name))
     }
 
+    /// Create a pod
+    ///
+    /// Pods are only supported by podman, see [`RunOption`] to run a container into a pod
+    ///
+    /// # Errors
+    ///
+    /// Fail if the runner does not support pods
+    /// Could fail if we cannot execute the command
+    pub async fn create_pod(&self, name: impl Into<String>) -> Result<String, RunnerError> {
+        let name = name.into();
+        match self {
+            Self::Docker(runner) => runner.create_pod(&name).await,
+            Self::Podman(runner) => runner.create_pod(&name).await,
+            Self::Nerdctl(runner) => runner.create_pod(&name).await,
+        }
+        .map_err(|source| RunnerError::CreatePodError {
+            runner: self.clone(),
+            name: name.clone(),
+            source: Box::new(source),
+        })?;
+
+        Ok(name)
+    }
+
     /// Create a container volume
     ///
     /// # Errors
@@ -272,8 +692,43 @@ impl Runner {
         Ok(ip.0)
     }
 
+    /// Get the container IP on the default bridge network
+    ///
+    /// # Errors
+    ///
+    /// Fail if the container uses the host or none network
+    /// Fail if the IP is not found
+    /// Could fail if we cannot execute the inspect command
+    pub async fn bridge_ip<I>(&self, container: &Container<I>) -> Result<IpAddr, RunnerError>
+    where
+        I: ToRunnableContainer,
+    {
+        self.guard_runner(container)?;
+
+        let id = container.id;
+        let ip = match self {
+            Self::Docker(runner) => runner.bridge_ip(id).await,
+            Self::Podman(runner) => runner.bridge_ip(id).await,
+            Self::Nerdctl(runner) => runner.bridge_ip(id).await,
+        }
+        .map_err(|source| RunnerError::FindNetworkIpError {
+            runner: self.clone(),
+            network: Box::new(Network::Bridge),
+            container: Box::new(id),
+            source: Box::new(source),
+        })?;
+
+        ip.ok_or_else(|| RunnerError::NoNetworkIp {
+            runner: self.clone(),
+            network: Box::new(Network::Bridge),
+            container: id,
+        })
+    }
+
     /// Get the container host ip
     ///
+    /// It's the address set with [`Runner::with_host_address`] if any.
+    ///
     /// # Errors
     ///
     /// Could fail if we cannot execute the inspect command
@@ -290,6 +745,71 @@ impl Runner {
         Ok(host_ip.0)
     }
 
+    /// List the running containers matching the label filter
+    ///
+    /// Use [`LabelFilter::default`] to list all running containers.
+    ///
+    /// # Errors
+    ///
+    /// Could fail if we cannot execute the ps command
+    pub async fn list_running_containers(
+        &self,
+        filter: &LabelFilter,
+    ) -> Result<Vec<ContainerProcess>, RunnerError> {
+        match self {
+            Self::Docker(runner) => runner.list_running_containers(filter).await,
+            Self::Podman(runner) => runner.list_running_containers(filter).await,
+            Self::Nerdctl(runner) => runner.list_running_containers(filter).await,
+        }
+        .map_err(|source| RunnerError::ListContainersError {
+            runner: self.clone(),
+            source: Box::new(source),
+        })
+    }
+
+    /// List the networks matching the label filter
+    ///
+    /// Use [`LabelFilter::default`] to list all networks.
+    ///
+    /// # Errors
+    ///
+    /// Could fail if we cannot execute the network command
+    pub async fn list_networks(&self, filter: &LabelFilter) -> Result<Vec<Network>, RunnerError> {
+        let networks = match self {
+            Self::Docker(runner) => runner.list_networks(filter).await,
+            Self::Podman(runner) => runner.list_networks(filter).await,
+            Self::Nerdctl(runner) => runner.list_networks(filter).await,
+        }
+        .map_err(|source| RunnerError::ListNetworksError {
+            runner: self.clone(),
+            source: Box::new(source),
+        })?;
+        let result = networks
+            .into_iter()
+            .map(|network| Network::Custom(network.name))
+            .collect();
+        Ok(result)
+    }
+
+    /// List the volumes matching the label filter
+    ///
+    /// Use [`LabelFilter::default`] to list all volumes.
+    ///
+    /// # Errors
+    ///
+    /// Could fail if we cannot execute the volume command
+    pub async fn list_volumes(&self, filter: &LabelFilter) -> Result<Vec<VolumeName>, RunnerError> {
+        match self {
+            Self::Docker(runner) => runner.list_volumes(filter).await,
+            Self::Podman(runner) => runner.list_volumes(filter).await,
+            Self::Nerdctl(runner) => runner.list_volumes(filter).await,
+        }
+        .map_err(|source| RunnerError::ListVolumesError {
+            runner: self.clone(),
+            source: Box::new(source),
+        })
+    }
+
     /// Execute a command into the container
     ///
     /// # Errors
@@ -305,8 +825,25 @@ impl Runner {
         I: ToRunnableContainer,
     {
         self.guard_runner(container)?;
+        self.exec_by_id(container.id, exec_command).await
+    }
 
-        let id = container.id;
+    /// Execute a command into a container from its id
+    ///
+    /// It's useful for containers not started by [`Runner::start`],
+    /// e.g. a compose service container, see [`crate::compose::ComposeContainers::service_container_id`].
+    ///
+    /// # Errors
+    ///
+    /// Could fail if we cannot execute the command
+    pub async fn exec_by_id<S>(
+        &self,
+        id: ContainerId,
+        exec_command: impl IntoIterator<Item = S> + Debug,
+    ) -> Result<String, RunnerError>
+    where
+        S: Into<String>,
+    {
         let exec_command = exec_command.into_iter().map(Into::into).collect();
         match self {
             Self::Docker(runner) => runner.exec(id, exec_command).await,
@@ -320,6 +857,525 @@ impl Runner {
         })
     }
 
+    /// Execute a command into the container, and capture the exit code, the stdout, and the stderr
+    ///
+    /// Unlike [`Runner::exec`], a non-zero exit code is not an error,
+    /// and the standard error is available, see [`ExecOutput`].
+    ///
+    /// # Errors
+    ///
+    /// Could fail if we cannot execute the command
+    pub async fn exec_output<I, S>(
+        &self,
+        container: &Container<I>,
+        exec_command: impl IntoIterator<Item = S> + Debug,
+    ) -> Result<ExecOutput, RunnerError>
+    where
+        S: Into<String>,
+        I: ToRunnableContainer,
+    {
+        self.guard_runner(container)?;
+
+        let id = container.id;
+        let exec_command = exec_command.into_iter().map(Into::into).collect();
+        match self {
+            Self::Docker(runner) => runner.exec_output(id, exec_command).await,
+            Self::Podman(runner) => runner.exec_output(id, exec_command).await,
+            Self::Nerdctl(runner) => runner.exec_output(id, exec_command).await,
+        }
+        .map_err(|source| RunnerError::ExecError {
+            runner: self.clone(),
+            id,
+            source: Box::new(source),
+        })
+    }
+
+    /// Retrieve the last log lines of the container
+    ///
+    /// Both stdout and stderr are provided, the stdout lines are followed by the stderr lines.
+    ///
+    /// # Errors
+    ///
+    /// Could fail if we cannot execute the logs command
+    pub async fn logs_tail<I>(
+        &self,
+        container: &Container<I>,
+        lines: usize,
+    ) -> Result<String, RunnerError>
+    where
+        I: ToRunnableContainer,
+    {
+        self.guard_runner(container)?;
+
+        let id = container.id;
+        match self {
+            Self::Docker(runner) => runner.tail_logs(id, lines).await,
+            Self::Podman(runner) => runner.tail_logs(id, lines).await,
+            Self::Nerdctl(runner) => runner.tail_logs(id, lines).await,
+        }
+        .map_err(|source| RunnerError::LogsError {
+            runner: self.clone(),
+            id,
+            source: Box::new(source),
+        })
+    }
+
+    /// Retrieve the log lines of the container produced since a time, or during the last duration
+    ///
+    /// Both stdout and stderr are provided, the stdout lines are followed by the stderr lines.
+    ///
+    /// # Errors
+    ///
+    /// Could fail if we cannot execute the logs command
+    pub async fn logs_since<I>(
+        &self,
+        container: &Container<I>,
+        since: impl Into<LogsSince>,
+    ) -> Result<String, RunnerError>
+    where
+        I: ToRunnableContainer,
+    {
+        self.guard_runner(container)?;
+
+        let id = container.id;
+        let since = since.into();
+        match self {
+            Self::Docker(runner) => runner.logs_since(id, since).await,
+            Self::Podman(runner) => runner.logs_since(id, since).await,
+            Self::Nerdctl(runner) => runner.logs_since(id, since).await,
+        }
+        .map_err(|source| RunnerError::LogsError {
+            runner: self.clone(),
+            id,
+            source: Box::new(source),
+        })
+    }
+
+    /// Watch the container resource usage
+    ///
+    /// A [`ContainerStats`] sample is sent approximately every second,
+    /// it's useful to sample the CPU or memory usage during a load phase.
+    /// The container must be running for the stream to produce samples.
+    ///
+    /// The stream is stopped when the receiver is dropped.
+    ///
+    /// # Errors
+    ///
+    /// Could fail if we cannot execute the stats command
+    pub async fn stats_stream<I>(
+        &self,
+        container: &Container<I>,
+    ) -> Result<mpsc::Receiver<ContainerStats>, RunnerError>
+    where
+        I: ToRunnableContainer,
+    {
+        self.guard_runner(container)?;
+
+        let id = container.id;
+        match self {
+            Self::Docker(runner) => runner.watch_stats(id, STATS_BUFFER_SIZE).await,
+            Self::Podman(runner) => runner.watch_stats(id, STATS_BUFFER_SIZE).await,
+            Self::Nerdctl(runner) => runner.watch_stats(id, STATS_BUFFER_SIZE).await,
+        }
+        .map_err(|source| RunnerError::StatsError {
+            runner: self.clone(),
+            id,
+            source: Box::new(source),
+        })
+    }
+
+    /// Build an image, and stream the build output
+    ///
+    /// The output lines are received with [`BuildStream::next_line`],
+    /// and the build result with [`BuildStream::result`].
+    ///
+    /// The build is stopped when the stream is dropped.
+    ///
+    /// # Errors
+    ///
+    /// Could fail if we cannot execute the build command
+    pub async fn build_stream(&self, image: &BuildImage) -> Result<BuildStream, RunnerError> {
+        let image_ref = ImageReference::from(image.image().clone());
+        let build = image.build_context();
+        let result = match self {
+            Self::Docker(runner) => {
+                runner
+                    .watch_build(&image_ref, &build, DEFAULT_LOG_BUFFER_SIZE)
+                    .await
+            }
+            Self::Podman(runner) => {
+                runner
+                    .watch_build(&image_ref, &build, DEFAULT_LOG_BUFFER_SIZE)
+                    .await
+            }
+            Self::Nerdctl(runner) => {
+                runner
+                    .watch_build(&image_ref, &build, DEFAULT_LOG_BUFFER_SIZE)
+                    .await
+            }
+        };
+        match result {
+            Ok((output, build)) => Ok(BuildStream {
+                runner: self.clone(),
+                image: image_ref.to_string(),
+                output,
+                build,
+            }),
+            Err(source) => Err(RunnerError::BuildError {
+                runner: self.clone(),
+                image: image_ref.to_string(),
+                source: Box::new(source),
+            }),
+        }
+    }
+
+    /// Inspect an image, e.g. to get the exposed ports, the default environment, or the labels
+    ///
+    /// The image should be available locally, it's not pulled.
+    ///
+    /// # Errors
+    ///
+    /// Could fail if the image does not exist, or if we cannot execute the inspect command
+    pub async fn inspect_image(&self, image: &ImageName) -> Result<ImageInspect, RunnerError> {
+        match self {
+            Self::Docker(runner) => runner.inspect_image(image).await,
+            Self::Podman(runner) => runner.inspect_image(image).await,
+            Self::Nerdctl(runner) => runner.inspect_image(image).await,
+        }
+        .map_err(|source| RunnerError::InspectImageError {
+            runner: self.clone(),
+            image: image.to_string(),
+            source: Box::new(source),
+        })
+    }
+
+    /// Read a file of the container
+    ///
+    /// The file is read with `cat`, so the container should provide this command.
+    ///
+    /// # Errors
+    ///
+    /// Fail with [`ContainerError::FileNotFound`] if the file does not exist
+    /// Could fail if we cannot execute the command
+    pub async fn read_file<I>(
+        &self,
+        container: &Container<I>,
+        path: impl AsRef<str>,
+    ) -> Result<Vec<u8>, RunnerError>
+    where
+        I: ToRunnableContainer,
+    {
+        self.guard_runner(container)?;
+
+        let id = container.id;
+        let path = path.as_ref();
+        match self {
+            Self::Docker(runner) => runner.read_file(id, path).await,
+            Self::Podman(runner) => runner.read_file(id, path).await,
+            Self::Nerdctl(runner) => runner.read_file(id, path).await,
+        }
+        .map_err(|source| RunnerError::ReadFileError {
+            runner: self.clone(),
+            id,
+            source: Box::new(source),
+        })
+    }
+
+    /// Write a file into the container
+    ///
+    /// The file is copied with the `cp` command of the runner,
+    /// an existing file is replaced.
+    ///
+    /// # Errors
+    ///
+    /// Fail with [`ContainerError::DirectoryNotFound`] if the parent directory does not exist
+    /// Could fail if we cannot execute the command
+    pub async fn write_file<I>(
+        &self,
+        container: &Container<I>,
+        path: impl AsRef<str>,
+        content: &[u8],
+    ) -> Result<(), RunnerError>
+    where
+        I: ToRunnableContainer,
+    {
+        self.guard_runner(container)?;
+
+        let id = container.id;
+        let path = path.as_ref();
+        match self {
+            Self::Docker(runner) => runner.write_file(id, path, content).await,
+            Self::Podman(runner) => runner.write_file(id, path, content).await,
+            Self::Nerdctl(runner) => runner.write_file(id, path, content).await,
+        }
+        .map_err(|source| RunnerError::WriteFileError {
+            runner: self.clone(),
+            id,
+            source: Box::new(source),
+        })
+    }
+
+    /// Inspect the container, and return the raw JSON
+    ///
+    /// # Errors
+    ///
+    /// Could fail if we cannot execute the inspect command
+    pub async fn inspect_raw<I>(
+        &self,
+        container: &Container<I>,
+    ) -> Result<serde_json::Value, RunnerError>
+    where
+        I: ToRunnableContainer,
+    {
+        self.guard_runner(container)?;
+
+        let id = container.id;
+        match self {
+            Self::Docker(runner) => runner.inspect_raw(id).await,
+            Self::Podman(runner) => runner.inspect_raw(id).await,
+            Self::Nerdctl(runner) => runner.inspect_raw(id).await,
+        }
+        .map_err(|source| RunnerError::InspectError {
+            runner: self.clone(),
+            id,
+            source: Box::new(source),
+        })
+    }
+
+    /// Get the container configuration, i.e. the `.Config` of the inspect command
+    ///
+    /// # Errors
+    ///
+    /// Could fail if we cannot execute the inspect command
+    pub async fn container_config<I>(
+        &self,
+        container: &Container<I>,
+    ) -> Result<ContainerConfig, RunnerError>
+    where
+        I: ToRunnableContainer,
+    {
+        self.guard_runner(container)?;
+
+        let id = container.id;
+        match self {
+            Self::Docker(runner) => runner.container_config(id).await,
+            Self::Podman(runner) => runner.container_config(id).await,
+            Self::Nerdctl(runner) => runner.container_config(id).await,
+        }
+        .map_err(|source| RunnerError::InspectError {
+            runner: self.clone(),
+            id,
+            source: Box::new(source),
+        })
+    }
+
+    /// Get the container creation time
+    ///
+    /// # Errors
+    ///
+    /// Could fail if we cannot execute the inspect command
+    pub async fn created_at<I>(&self, container: &Container<I>) -> Result<SystemTime, RunnerError>
+    where
+        I: ToRunnableContainer,
+    {
+        self.guard_runner(container)?;
+
+        let id = container.id;
+        match self {
+            Self::Docker(runner) => runner.created_at(id).await,
+            Self::Podman(runner) => runner.created_at(id).await,
+            Self::Nerdctl(runner) => runner.created_at(id).await,
+        }
+        .map_err(|source| RunnerError::InspectError {
+            runner: self.clone(),
+            id,
+            source: Box::new(source),
+        })
+    }
+
+    /// Get the container health check log
+    ///
+    /// It contains the last health check probes results,
+    /// useful to understand why a container is unhealthy.
+    /// The log is empty if the container does not have a health check.
+    ///
+    /// # Errors
+    ///
+    /// Could fail if we cannot execute the inspect command
+    pub async fn health_log<I>(
+        &self,
+        container: &Container<I>,
+    ) -> Result<Vec<HealthLogEntry>, RunnerError>
+    where
+        I: ToRunnableContainer,
+    {
+        self.guard_runner(container)?;
+
+        let id = container.id;
+        match self {
+            Self::Docker(runner) => runner.health_log(id).await,
+            Self::Podman(runner) => runner.health_log(id).await,
+            Self::Nerdctl(runner) => runner.health_log(id).await,
+        }
+        .map_err(|source| RunnerError::InspectError {
+            runner: self.clone(),
+            id,
+            source: Box::new(source),
+        })
+    }
+
+    /// Get the container (last) start time
+    ///
+    /// # Errors
+    ///
+    /// Could fail if we cannot execute the inspect command
+    pub async fn started_at<I>(&self, container: &Container<I>) -> Result<SystemTime, RunnerError>
+    where
+        I: ToRunnableContainer,
+    {
+        self.guard_runner(container)?;
+
+        let id = container.id;
+        match self {
+            Self::Docker(runner) => runner.started_at(id).await,
+            Self::Podman(runner) => runner.started_at(id).await,
+            Self::Nerdctl(runner) => runner.started_at(id).await,
+        }
+        .map_err(|source| RunnerError::InspectError {
+            runner: self.clone(),
+            id,
+            source: Box::new(source),
+        })
+    }
+
+    /// Get the container exit code
+    ///
+    /// Returns `None` if the container has not exited yet, e.g. it's still running.
+    ///
+    /// # Errors
+    ///
+    /// Could fail if we cannot execute the inspect command
+    pub async fn exit_code<I>(&self, container: &Container<I>) -> Result<Option<i32>, RunnerError>
+    where
+        I: ToRunnableContainer,
+    {
+        self.guard_runner(container)?;
+
+        let id = container.id;
+        match self {
+            Self::Docker(runner) => runner.exit_code(id).await,
+            Self::Podman(runner) => runner.exit_code(id).await,
+            Self::Nerdctl(runner) => runner.exit_code(id).await,
+        }
+        .map_err(|source| RunnerError::InspectError {
+            runner: self.clone(),
+            id,
+            source: Box::new(source),
+        })
+    }
+
+    /// Wait until the container is removed
+    ///
+    /// After a stop, a container started with the `remove` option (`--rm`) still exists for a while.
+    /// The container is polled by id until it no longer exists.
+    ///
+    /// # Errors
+    ///
+    /// Fail if the container still exists after the timeout
+    /// Could fail if we cannot execute the ps command
+    pub async fn wait_removed<I>(
+        &self,
+        container: &Container<I>,
+        timeout: Duration,
+    ) -> Result<(), RunnerError>
+    where
+        I: ToRunnableContainer,
+    {
+        self.guard_runner(container)?;
+
+        let id = container.id;
+        match self {
+            Self::Docker(runner) => runner.wait_removed(id, timeout).await,
+            Self::Podman(runner) => runner.wait_removed(id, timeout).await,
+            Self::Nerdctl(runner) => runner.wait_removed(id, timeout).await,
+        }
+        .map_err(|source| RunnerError::WaitRemovedError {
+            runner: self.clone(),
+            id,
+            source: Box::new(source),
+        })
+    }
+
+    /// Stop and remove the container, see [`ContainerGuard::cleanup`]
+    pub(crate) async fn stop_and_remove<I>(
+        &self,
+        container: &Container<I>,
+    ) -> Result<(), RunnerError>
+    where
+        I: ToRunnableContainer,
+    {
+        self.guard_runner(container)?;
+
+        let id = container.id;
+        match self {
+            Self::Docker(runner) => runner.stop_and_remove(id).await,
+            Self::Podman(runner) => runner.stop_and_remove(id).await,
+            Self::Nerdctl(runner) => runner.stop_and_remove(id).await,
+        }
+        .map_err(|source| RunnerError::StopError {
+            runner: self.clone(),
+            id,
+            source: Box::new(source),
+        })
+    }
+
+    /// Stop and remove all the containers started by this runner in the current process
+    ///
+    /// It includes the containers started by a clone of this runner,
+    /// but not the containers started by another runner instance, e.g. created with [`Runner::auto`].
+    /// The detached containers, and the already dropped containers are skipped.
+    /// The stopped containers are not stopped again during the drop.
+    ///
+    /// ```rust, no_run
+    /// # async fn run() -> anyhow::Result<()> {
+    /// use rustainers::images::{Postgres, Redis};
+    /// use rustainers::runner::Runner;
+    ///
+    /// let runner = Runner::auto()?;
+    /// let redis = runner.start(Redis::default()).await?;
+    /// let postgres = runner.start(Postgres::default()).await?;
+    /// // ...
+    /// runner.stop_all().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Fail with the first error if a container cannot be stopped, or removed,
+    /// the other containers are still stopped
+    pub async fn stop_all(&self) -> Result<(), RunnerError> {
+        let mut result = Ok(());
+        for id in CONTAINER_REGISTRY.take_running(self) {
+            let stopped = match self {
+                Self::Docker(runner) => runner.stop_and_remove(id).await,
+                Self::Podman(runner) => runner.stop_and_remove(id).await,
+                Self::Nerdctl(runner) => runner.stop_and_remove(id).await,
+            };
+            if let Err(source) = stopped {
+                warn!(%id, "Fail to stop the container because {source}");
+                if result.is_ok() {
+                    result = Err(RunnerError::StopError {
+                        runner: self.clone(),
+                        id,
+                        source: Box::new(source),
+                    });
+                }
+            }
+        }
+        result
+    }
+
     /// Stop the container
     ///
     /// This method is call during the [`crate::Container`] drop if it's not detached
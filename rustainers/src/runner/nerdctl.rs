@@ -1,36 +1,72 @@
 use std::fmt::Display;
+use std::net::IpAddr;
+use std::sync::Arc;
 
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use tracing::debug;
 
-use super::{InnerRunner, RunnerError};
+use super::{probe_version, InnerRunner, RunnerConfig, RunnerError};
 use crate::cmd::Cmd;
+use crate::runner::DOCKER_CONFIG_ENV;
 use crate::version::Version;
 
 const MINIMAL_VERSION: Version = Version::new(1, 5);
 
+/// The containerd namespace environment variable
+const CONTAINERD_NAMESPACE_ENV: &str = "CONTAINERD_NAMESPACE";
+
 /// A Nerdctl runner
 ///
 /// This runner use the nerdctl CLI
 ///
 /// It requires nerdctl client v1.5+
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Nerdctl {
     /// The nerdctl version
     pub version: Version,
+
+    /// The runner settings
+    pub(crate) config: Arc<RunnerConfig>,
+}
+
+impl Nerdctl {
+    /// The containerd namespace, if it's not the nerdctl default
+    #[must_use]
+    pub fn namespace(&self) -> Option<&str> {
+        self.config.namespace.as_deref()
+    }
 }
 
 #[async_trait]
 impl InnerRunner for Nerdctl {
+    fn stop_timeout(&self) -> Option<u16> {
+        self.config.stop_timeout
+    }
+
+    fn host_address(&self) -> Option<IpAddr> {
+        self.config.host_address
+    }
+
     fn command(&self) -> Cmd<'static> {
-        Cmd::new("nerdctl")
+        let mut cmd = Cmd::new("nerdctl");
+        if let Some(config_dir) = &self.config.config_dir {
+            cmd.push_safe_env(DOCKER_CONFIG_ENV, config_dir.display().to_string());
+        }
+        if let Some(namespace) = &self.config.namespace {
+            cmd.push_safe_env(CONTAINERD_NAMESPACE_ENV, namespace.as_str());
+        }
+        cmd
     }
 }
 
 impl Display for Nerdctl {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Nerdctl {}", self.version)
+        write!(f, "Nerdctl {}", self.version)?;
+        if let Some(namespace) = &self.config.namespace {
+            write!(f, " - namespace {namespace}")?;
+        }
+        Ok(())
     }
 }
 
@@ -38,7 +74,7 @@ pub(super) fn create() -> Result<Nerdctl, RunnerError> {
     // Check binary version
     let mut cmd = Cmd::new("nerdctl");
     cmd.push_args(["version", "--format", "json"]);
-    let Ok(Some(version)) = cmd.json_blocking::<Option<NerdctlVersion>>() else {
+    let Ok(Some(version)) = probe_version::<Option<NerdctlVersion>>(&cmd) else {
         return Err(RunnerError::CommandNotAvailable(String::from("nerdctl")));
     };
     debug!("Found docker version: {version:#?}");
@@ -53,7 +89,10 @@ pub(super) fn create() -> Result<Nerdctl, RunnerError> {
         });
     }
 
-    Ok(Nerdctl { version: current })
+    Ok(Nerdctl {
+        version: current,
+        config: Arc::default(),
+    })
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -80,6 +119,20 @@ mod tests {
         insta::assert_debug_snapshot!(version);
     }
 
+    #[test]
+    fn should_set_namespace() {
+        let nerdctl = Nerdctl {
+            version: Version::new(1, 7),
+            config: Arc::new(RunnerConfig {
+                namespace: Some(String::from("ci")),
+                ..RunnerConfig::default()
+            }),
+        };
+        let cmd = nerdctl.command();
+        assert2::check!(cmd.to_string() == "CONTAINERD_NAMESPACE=ci nerdctl");
+        assert2::check!(nerdctl.to_string() == "Nerdctl 1.7 - namespace ci");
+    }
+
     #[cfg(feature = "ensure-nerdctl")]
     #[test]
     fn should_works() {
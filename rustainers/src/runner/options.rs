@@ -1,53 +1,258 @@
+use std::fmt::{self, Debug};
+use std::path::PathBuf;
+use std::sync::Arc;
 use std::time::Duration;
 
 use indexmap::IndexMap;
 use typed_builder::TypedBuilder;
 
-use crate::{Network, Volume};
+use crate::io::DEFAULT_LOG_BUFFER_SIZE;
+use crate::{ContainerId, Network, Volume};
 
 /// Run options
 ///
 /// Available options:
 ///
 /// * `wait_interval`: wait until re-check a container state (default 500ms)
+/// * `start_timeout`: the maximum time to start the container, including the pull, and the wait strategies (default unbounded)
+/// * `log_buffer_size`: the number of log lines buffered while waiting for a log line (default 256)
 /// * `remove`: if we remove the container after the stop (`--rm` flag, default false)
+/// * `keep_on_failure`: keep the container alive if it's dropped while panicking, see [`RunOptionBuilder::with_keep_on_failure`]
+/// * `init`: run an init process inside the container that reaps zombie processes (`--init` flag, default false)
 /// * `name`: provide the container name (default unnamed, use the runner name)
+/// * `name_prefix`: prefix the generated container name when no `name` is provided (default unnamed, use the runner name)
+/// * `platform`: the image platform, e.g. `linux/amd64` (`--platform` option, default the host platform)
+/// * `check_emulation`: check the emulation is available for a foreign `platform` before the run (default false)
 /// * `network`: define the network
+/// * `additional_networks`: connect the container to other networks once it's started, a reused container is also connected to the missing networks
+/// * `pod`: run the container into a pod (`--pod` option, only supported by podman)
 /// * `volumes`: set some volumes
+/// * `docker_socket`: bind mount the runner socket, see [`RunOptionBuilder::with_docker_socket`]
 /// * `env`: set some environment variables
+/// * `env_files`: read environment variables from files (`--env-file` option), see [`RunOptionBuilder::with_env_file`]
+/// * `group_add`: add supplementary groups to the container process (`--group-add` option), see [`RunOptionBuilder::with_group_add`]
+/// * `sysctls`: set some namespaced kernel parameters (`--sysctl` option), see [`RunOptionBuilder::with_sysctl`]
+/// * `oom_kill_disable`: disable the OOM killer (`--oom-kill-disable` flag, default false)
+/// * `oom_score_adj`: tune the OOM killer preference (`--oom-score-adj` option, default the runner default)
+/// * `tty`: allocate a pseudo-TTY (`--tty` flag, default false)
+/// * `interactive`: keep the standard input open (`--interactive` flag, default false)
+/// * `cgroup_parent`: the parent cgroup of the container (`--cgroup-parent` option, default the runner default)
+/// * `cgroupns`: the cgroup namespace mode (`--cgroupns` option, default the runner default)
+/// * `on_started`: a callback invoked when the container is ready, see [`RunOptionBuilder::with_on_started`]
 #[derive(Debug, Clone, TypedBuilder)]
 #[builder(field_defaults(default, setter(prefix = "with_")))]
+#[allow(clippy::struct_excessive_bools)]
 pub struct RunOption {
     /// Wait interval for container health check
     #[builder(default = Duration::from_millis(500))]
     pub(super) wait_interval: Duration,
 
+    /// The maximum time to start the container
+    ///
+    /// It bounds the whole start sequence, i.e. the image pull (or build), the container creation,
+    /// and the wait strategies, so a misbehaving container cannot hang a test suite.
+    /// On timeout, the running commands are killed, and the container created by the start is stopped and removed,
+    /// a reused container is kept.
+    /// Note that an unnamed container could be left behind if the timeout happens during its creation.
+    #[builder(setter(strip_option))]
+    pub(super) start_timeout: Option<Duration>,
+
+    /// The capacity of the channel used to watch the container logs
+    ///
+    /// A zero capacity is not supported by the channel, so it's raised to 1
+    #[builder(default = DEFAULT_LOG_BUFFER_SIZE, setter(transform = |size: usize| size.max(1)))]
+    pub(super) log_buffer_size: usize,
+
     /// Automatically remove the container when it exits
     pub(super) remove: bool,
 
+    /// Keep the container alive when it's dropped during a panic, e.g. a failing test
+    ///
+    /// The container is still stopped on success, but it's detached on failure
+    /// to inspect its state (logs, files, ...) after the test.
+    /// See [`Container::detach`](crate::Container::detach)
+    pub(crate) keep_on_failure: bool,
+
+    /// Run an init process (e.g. tini) as PID 1
+    pub(super) init: bool,
+
     /// Assign a name to the container
     #[builder(setter(into, strip_option))]
     pub(super) name: Option<String>,
 
+    /// Prefix the generated container name, e.g. `ci` for `ci-<ulid>`
+    ///
+    /// It makes the containers easy to spot, or to prune.
+    /// It's ignored if the `name` is set.
+    #[builder(setter(into, strip_option))]
+    pub(super) name_prefix: Option<String>,
+
+    /// The image platform, e.g. `linux/amd64`
+    #[builder(setter(into, strip_option))]
+    pub(crate) platform: Option<String>,
+
+    /// Check the emulation (e.g. QEMU with `binfmt_misc`) is available before the run
+    ///
+    /// It's only checked when the `platform` architecture is not the host architecture,
+    /// by running a tiny container with the platform.
+    /// Without emulation, the container fails with an opaque `exec format error`.
+    pub(crate) check_emulation: bool,
+
     /// The network
     #[builder(default, setter(into))]
     pub(crate) network: Option<Network>,
 
+    /// The additional networks
+    #[builder(default, setter(transform = |args: impl IntoIterator<Item = impl Into<Network>>| args.into_iter().map(Into::into).collect()))]
+    pub(crate) additional_networks: Vec<Network>,
+
+    /// The pod
+    #[builder(setter(into, strip_option))]
+    pub(crate) pod: Option<String>,
+
     /// Volumes
     #[builder(default, setter(transform = |args: impl IntoIterator<Item = impl Into<Volume>>| args.into_iter().map(Into::into).collect()))]
     pub(crate) volumes: Vec<Volume>,
 
+    /// Bind mount the runner socket
+    #[builder(via_mutators(init = false), mutators(
+        /// Bind mount the runner socket (e.g. `/var/run/docker.sock`, or the podman socket)
+        ///
+        /// The socket is mounted read-only to `/var/run/docker.sock` inside the container,
+        /// so the tools that drive docker themselves could be tested.
+        ///
+        /// ⚠️ WARNING, the read-only mount does not restrict the API access,
+        /// the container could drive the runner, i.e. start privileged containers, and access the host.
+        /// Use it only with trusted images.
+        pub fn with_docker_socket(&mut self) {
+            self.docker_socket = true;
+        }
+    ))]
+    pub(crate) docker_socket: bool,
+
     /// The environment variables
     #[builder(setter(transform = |args: impl IntoIterator<Item = (impl Into<String>, impl Into<String>)>| args.into_iter().map(|(key, value)| (key.into(), value.into())).collect()))]
     pub(crate) env: IndexMap<String, String>,
 
+    /// The environment files
+    #[builder(via_mutators(init = Vec::new()), mutators(
+        /// Read environment variables from a file (`--env-file path`), could be called multiple times
+        ///
+        /// The file contains a `KEY=value` per line.
+        /// It's combined with the `env` variables, that take precedence over the file variables.
+        pub fn with_env_file(&mut self, path: impl Into<PathBuf>) {
+            self.env_files.push(path.into());
+        }
+    ))]
+    pub(crate) env_files: Vec<PathBuf>,
+
+    /// The supplementary groups
+    #[builder(via_mutators(init = Vec::new()), mutators(
+        /// Add a supplementary group (`--group-add group`), could be called multiple times
+        ///
+        /// The group is a name or a GID, e.g. to access a bind-mounted socket owned by the `docker` group.
+        pub fn with_group_add(&mut self, group: impl Into<String>) {
+            self.group_add.push(group.into());
+        }
+    ))]
+    pub(crate) group_add: Vec<String>,
+
+    /// The namespaced kernel parameters
+    #[builder(via_mutators(init = IndexMap::new()), mutators(
+        /// Set a namespaced kernel parameter (`--sysctl key=value`), could be called multiple times
+        ///
+        /// Only namespaced kernel parameters are supported,
+        /// i.e. IPC parameters (`kernel.msgmax`, `kernel.sem`, `kernel.shm*`, `fs.mqueue.*`),
+        /// and network parameters (`net.*`) if the container does not use the host network.
+        /// Other parameters, like `vm.max_map_count`, are only configurable on the host.
+        pub fn with_sysctl(&mut self, key: impl Into<String>, value: impl Into<String>) {
+            self.sysctls.insert(key.into(), value.into());
+        }
+    ))]
+    pub(crate) sysctls: IndexMap<String, String>,
+
+    /// Disable the OOM killer
+    ///
+    /// It requires a memory limit, otherwise the host could run out of memory.
+    /// Note that it's only supported with cgroups v1, the runner ignores it with cgroups v2.
+    pub(crate) oom_kill_disable: bool,
+
+    /// The OOM killer preference, from `-1000` (never killed) to `1000` (killed first)
+    ///
+    /// Note that a negative value requires privileges, e.g. it's not allowed in rootless mode.
+    #[builder(setter(strip_option))]
+    pub(crate) oom_score_adj: Option<i32>,
+
+    /// Allocate a pseudo-TTY
+    ///
+    /// Some images behave differently with a TTY, e.g. colored output, or line buffering.
+    /// Note that the runner merges the standard error into the standard output with a TTY,
+    /// so a wait strategy on the standard error logs does not match anymore.
+    pub(crate) tty: bool,
+
+    /// Keep the standard input open
+    ///
+    /// The container is still detached, so nothing is attached to the standard input,
+    /// it's only useful for processes that exit when the standard input is closed (e.g. a shell, or `cat`).
+    /// Combined with [`RunOptionBuilder::with_tty`], it keeps an interactive shell running.
+    pub(crate) interactive: bool,
+
     /// The command (override the runable command)
     #[builder(default, setter(transform = |args: impl IntoIterator<Item = impl Into<String>>| Some(args.into_iter().map(Into::into).collect())))]
     pub(crate) command: Option<Vec<String>>,
 
+    /// The parent cgroup of the container
+    ///
+    /// With the systemd cgroup driver, it should be a slice, e.g. `my-tests.slice`.
+    #[builder(setter(into, strip_option))]
+    pub(crate) cgroup_parent: Option<String>,
+
+    /// The cgroup namespace mode
+    ///
+    /// e.g. the images running systemd, or nested containers, could need a private cgroup namespace.
+    /// Note that it's only supported with cgroups v2 for the `private` mode.
+    #[builder(setter(strip_option))]
+    pub(crate) cgroupns: Option<CgroupNamespace>,
+
     /// The entrypoint (override the image entrypoint)
     #[builder(default, setter(into, strip_option))]
     pub(crate) entrypoint: Option<String>,
+
+    /// A callback invoked with the container id when the container is ready
+    ///
+    /// The container is running, the wait strategies are satisfied, and the ports are bound,
+    /// e.g. to seed some data, or to register the container in a service discovery.
+    /// Note that it's also invoked when an existing container is reused (see `name`).
+    #[builder(setter(transform = |callback: impl Fn(&ContainerId) + Send + Sync + 'static| Some(ContainerCallback(Arc::new(callback)))))]
+    pub(crate) on_started: Option<ContainerCallback>,
+}
+
+/// The cgroup namespace mode, see [`RunOptionBuilder::with_cgroupns`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum_macros::Display)]
+#[strum(serialize_all = "lowercase")]
+#[non_exhaustive]
+pub enum CgroupNamespace {
+    /// Use the host cgroup namespace
+    Host,
+
+    /// Use a private cgroup namespace
+    Private,
+}
+
+/// A container lifecycle callback, see [`RunOptionBuilder::with_on_started`]
+#[derive(Clone)]
+pub struct ContainerCallback(Arc<dyn Fn(&ContainerId) + Send + Sync>);
+
+impl ContainerCallback {
+    pub(crate) fn call(&self, id: &ContainerId) {
+        (self.0)(id);
+    }
+}
+
+impl Debug for ContainerCallback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ContainerCallback")
+    }
 }
 
 impl RunOption {
@@ -69,3 +274,16 @@ impl Default for RunOption {
         RunOption::builder().build()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use assert2::check;
+
+    use super::*;
+
+    #[test]
+    fn should_raise_zero_log_buffer_size() {
+        let options = RunOption::builder().with_log_buffer_size(0).build();
+        check!(options.log_buffer_size == 1);
+    }
+}
@@ -1,8 +1,11 @@
+use std::env;
 use std::fmt::Display;
+use std::net::IpAddr;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
-use std::path::Path;
 use tracing::{debug, info};
 
 use crate::cmd::Cmd;
@@ -12,7 +15,10 @@ use crate::ContainerProcess;
 use crate::IpamNetworkConfig;
 use crate::NetworkInfo;
 
-use super::{ContainerError, InnerRunner, RunnerError};
+use super::{probe_version, ContainerError, InnerRunner, LabelFilter, RunnerConfig, RunnerError};
+
+const REGISTRY_AUTH_FILE_ENV: &str = "REGISTRY_AUTH_FILE";
+
 const MINIMAL_VERSION: Version = Version::new(4, 0);
 const COMPOSE_MINIMAL_VERSION: Version = Version::new(1, 0);
 
@@ -22,20 +28,103 @@ const COMPOSE_MINIMAL_VERSION: Version = Version::new(1, 0);
 ///
 /// It requires podman client v4.0+
 ///
-/// podman-compose is supported if v1.0+
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The built-in `podman compose` is preferred if available,
+/// otherwise podman-compose is supported if v1.0+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Podman {
     /// The client version
     pub version: Version,
 
-    /// The podman-compose version
+    /// The compose command
+    pub compose_command: PodmanComposeCommand,
+
+    /// The compose version
     pub compose_version: Option<Version>,
+
+    /// The runner settings
+    pub(crate) config: Arc<RunnerConfig>,
+}
+
+/// The podman compose command
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PodmanComposeCommand {
+    /// The built-in `podman compose` subcommand
+    Native,
+
+    /// The standalone `podman-compose` command
+    PodmanCompose,
+}
+
+impl PodmanComposeCommand {
+    pub(crate) fn command(self) -> Cmd<'static> {
+        match self {
+            Self::Native => {
+                let mut cmd = Cmd::new("podman");
+                cmd.push_arg("compose");
+                cmd
+            }
+            Self::PodmanCompose => Cmd::new("podman-compose"),
+        }
+    }
+}
+
+impl Display for PodmanComposeCommand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Native => write!(f, "podman compose"),
+            Self::PodmanCompose => write!(f, "podman-compose"),
+        }
+    }
+}
+
+impl Podman {
+    /// Podman does not read the `DOCKER_CONFIG`, we use the `config.json` as the auth file
+    pub(crate) fn push_auth_env(&self, cmd: &mut Cmd<'_>) {
+        if let Some(config_dir) = &self.config.config_dir {
+            let auth_file = config_dir.join("config.json");
+            cmd.push_safe_env(REGISTRY_AUTH_FILE_ENV, auth_file.display().to_string());
+        }
+    }
 }
 
 #[async_trait]
 impl InnerRunner for Podman {
+    fn stop_timeout(&self) -> Option<u16> {
+        self.config.stop_timeout
+    }
+
+    fn host_address(&self) -> Option<IpAddr> {
+        self.config.host_address
+    }
+
     fn command(&self) -> Cmd<'static> {
-        Cmd::new("podman")
+        let mut cmd = Cmd::new("podman");
+        self.push_auth_env(&mut cmd);
+        cmd
+    }
+
+    fn supports_pod(&self) -> bool {
+        true
+    }
+
+    /// The podman socket, requires the `podman.socket` service
+    ///
+    /// It's the `CONTAINER_HOST` unix socket,
+    /// or the rootless socket `$XDG_RUNTIME_DIR/podman/podman.sock` if it exists,
+    /// or the rootful socket `/run/podman/podman.sock`
+    fn socket_path(&self) -> PathBuf {
+        if let Some(path) = env::var("CONTAINER_HOST")
+            .ok()
+            .as_deref()
+            .and_then(|host| host.strip_prefix("unix://"))
+        {
+            return PathBuf::from(path);
+        }
+        env::var_os("XDG_RUNTIME_DIR")
+            .map(|dir| Path::new(&dir).join("podman/podman.sock"))
+            .filter(|path| path.exists())
+            .unwrap_or_else(|| PathBuf::from("/run/podman/podman.sock"))
     }
 
     #[tracing::instrument(level = "info", skip(self), fields(runner = %self))]
@@ -45,9 +134,7 @@ impl InnerRunner for Podman {
 
     #[tracing::instrument(level = "debug", skip(self), fields(runner = %self))]
     async fn list_custom_networks(&self) -> Result<Vec<NetworkInfo>, ContainerError> {
-        let mut cmd: Cmd<'_> = self.command();
-        cmd.push_args(["network", "ls", "--no-trunc", "--format={{json .}}"]);
-        let mut result = cmd.json_stream::<NetworkInfo>().await?;
+        let mut result = self.list_networks(&LabelFilter::default()).await?;
         result.retain(|x| "podman" == x.name);
         Ok(result)
     }
@@ -72,17 +159,45 @@ impl InnerRunner for Podman {
             "--format=json",
         ]);
 
-        let containers = cmd.json::<Vec<ContainerProcess>>().await?;
+        let containers = cmd.json_list::<ContainerProcess>().await?;
         let result = containers.into_iter().find(|it| it.names.contains(name));
         Ok(result)
     }
+
+    #[tracing::instrument(level = "debug", skip(self, id), fields(runner = %self, id = %id))]
+    async fn ps_by_id(&self, id: ContainerId) -> Result<Option<ContainerProcess>, ContainerError> {
+        let mut cmd = self.command();
+        cmd.push_args(["ps", "--all", "--no-trunc", "--filter"]);
+        cmd.push_arg(format!("id={id}"));
+        cmd.push_arg("--format=json");
+
+        let containers = cmd.json_list::<ContainerProcess>().await?;
+        let result = containers.into_iter().find(|it| it.id == id);
+        Ok(result)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), fields(runner = %self))]
+    async fn list_running_containers(
+        &self,
+        filter: &LabelFilter,
+    ) -> Result<Vec<ContainerProcess>, ContainerError> {
+        let mut cmd = self.command();
+        cmd.push_args(["ps", "--no-trunc", "--format=json"]);
+        filter.push_args(&mut cmd);
+        let containers = cmd.json_list::<ContainerProcess>().await?;
+        Ok(containers)
+    }
 }
 
 impl Display for Podman {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "Podman {}", self.version)?;
         if let Some(compose_version) = self.compose_version {
-            write!(f, " - podman-compose {compose_version}")?;
+            if let Some(binary) = &self.config.compose_binary {
+                write!(f, " - {binary} {compose_version}")?;
+            } else {
+                write!(f, " - {} {compose_version}", self.compose_command)?;
+            }
         }
         Ok(())
     }
@@ -91,12 +206,14 @@ pub(super) fn create() -> Result<Podman, RunnerError> {
     // Check binary version
     let mut cmd = Cmd::new("podman");
     cmd.push_args(["version", "--format", "json"]);
-    let Ok(Some(version)) = cmd.json_blocking::<Option<PodmanVersion>>() else {
+    let Ok(Some(version)) = probe_version::<Option<PodmanVersion>>(&cmd) else {
         return Err(RunnerError::CommandNotAvailable(String::from("podman")));
     };
 
-    // Check client version
-    let current = version.client.api_version;
+    // Check client version, fallback to the server version
+    let Some(current) = version.api_version() else {
+        return Err(RunnerError::CommandNotAvailable(String::from("podman")));
+    };
     debug!("Found podman version: {current}");
     if current < MINIMAL_VERSION {
         return Err(RunnerError::UnsupportedVersion {
@@ -106,32 +223,44 @@ pub(super) fn create() -> Result<Podman, RunnerError> {
         });
     }
 
-    let compose_version = compose_version();
+    // Prefer the built-in compose, fallback to podman-compose
+    let (compose_command, compose_version) = [
+        PodmanComposeCommand::Native,
+        PodmanComposeCommand::PodmanCompose,
+    ]
+    .into_iter()
+    .find_map(|command| compose_version(command).map(|version| (command, version)))
+    .map_or(
+        (PodmanComposeCommand::PodmanCompose, None),
+        |(command, version)| (command, Some(version)),
+    );
 
     Ok(Podman {
         version: current,
+        compose_command,
         compose_version,
+        config: Arc::default(),
     })
 }
 
-fn compose_version() -> Option<Version> {
+fn compose_version(command: PodmanComposeCommand) -> Option<Version> {
     // Check the help command not fail
-    let mut cmd = Cmd::new("podman-compose");
+    let mut cmd = command.command();
     cmd.ignore_stderr();
     cmd.push_args(["version", "--format", "json"]);
     let Ok(result) = cmd.result_blocking() else {
-        debug!("Fail to check podman-compose version");
+        debug!("Fail to check {command} version");
         return None;
     };
 
     let Ok(Some(compose_version)) = extract_podman_compose_version(&result) else {
-        debug!("Invalid podman-compose version, {result}");
+        debug!("Invalid {command} version, {result}");
         return None;
     };
 
     // Check minimal version
     let version = compose_version.version;
-    debug!("Podman compose version: {version}");
+    debug!("Podman compose ({command}) version: {version}");
     if version < COMPOSE_MINIMAL_VERSION {
         info!(
             "Podman compose version {version} is not supported, require to be >= {COMPOSE_MINIMAL_VERSION}"
@@ -142,6 +271,31 @@ fn compose_version() -> Option<Version> {
     Some(version)
 }
 
+/// Check the version of a compose binary, e.g. a `podman-compose` wrapper
+pub(super) fn compose_binary_version(binary: &str) -> Result<Version, RunnerError> {
+    let mut cmd = Cmd::new(binary.to_string());
+    cmd.ignore_stderr();
+    cmd.push_args(["version", "--format", "json"]);
+    let Some(Some(compose_version)) = cmd
+        .result_blocking()
+        .ok()
+        .and_then(|result| extract_podman_compose_version(&result).ok())
+    else {
+        return Err(RunnerError::CommandNotAvailable(binary.to_string()));
+    };
+
+    let current = compose_version.version;
+    debug!("Podman compose ({binary}) version: {current}");
+    if current < COMPOSE_MINIMAL_VERSION {
+        return Err(RunnerError::UnsupportedVersion {
+            command: binary.to_string(),
+            current,
+            minimal: COMPOSE_MINIMAL_VERSION,
+        });
+    }
+    Ok(current)
+}
+
 fn extract_podman_compose_version(
     output: &str,
 ) -> Result<Option<PodmanComposeVersion>, serde_json::Error> {
@@ -157,10 +311,20 @@ fn extract_podman_compose_version(
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 struct PodmanVersion {
-    client: PodmanVersionItem,
+    #[serde(default)]
+    client: Option<PodmanVersionItem>,
+    #[serde(default)]
     server: Option<PodmanVersionItem>,
 }
 
+impl PodmanVersion {
+    /// The client API version, or the server API version if the client does not provide it
+    fn api_version(&self) -> Option<Version> {
+        let client = self.client.as_ref().and_then(|it| it.api_version);
+        client.or_else(|| self.server.as_ref().and_then(|it| it.api_version))
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct PodmanComposeVersion {
     version: Version,
@@ -169,9 +333,10 @@ struct PodmanComposeVersion {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 struct PodmanVersionItem {
-    #[serde(rename = "APIVersion")]
-    api_version: Version,
-    version: Version,
+    #[serde(rename = "APIVersion", default)]
+    api_version: Option<Version>,
+    #[serde(default)]
+    version: Option<Version>,
 }
 
 #[cfg(test)]
@@ -196,6 +361,14 @@ mod tests {
         insta::assert_debug_snapshot!(version);
     }
 
+    #[test]
+    fn should_serde_native_compose() {
+        let output = include_str!("../../tests/assets/podman_compose_version.txt");
+        let result = extract_podman_compose_version(output);
+        let_assert!(Ok(Some(version)) = result);
+        insta::assert_debug_snapshot!(version);
+    }
+
     #[cfg(feature = "ensure-podman")]
     #[test]
     fn should_works() {
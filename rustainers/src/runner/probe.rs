@@ -0,0 +1,102 @@
+use std::time::Duration;
+
+use serde::de::DeserializeOwned;
+use tracing::debug;
+
+use crate::cmd::{Cmd, CommandError};
+
+/// The delays between the runner version probe attempts (350ms in total)
+///
+/// A just-started daemon (e.g. in CI) could transiently fail to answer.
+const VERSION_PROBE_DELAYS: [Duration; 3] = [
+    Duration::from_millis(50),
+    Duration::from_millis(100),
+    Duration::from_millis(200),
+];
+
+/// Run the version command, with a short bounded retry when the command fails
+///
+/// A missing command is not retried.
+pub(super) fn probe_version<T>(cmd: &Cmd<'_>) -> Result<T, CommandError>
+where
+    T: DeserializeOwned,
+{
+    retry_probe(&VERSION_PROBE_DELAYS, || cmd.clone().json_blocking::<T>())
+}
+
+fn retry_probe<T>(
+    delays: &[Duration],
+    mut probe: impl FnMut() -> Result<T, CommandError>,
+) -> Result<T, CommandError> {
+    for delay in delays {
+        match probe() {
+            Err(err @ CommandError::CommandFail { .. }) => {
+                debug!(%err, "Runner not ready, retrying in {delay:?}");
+                std::thread::sleep(*delay);
+            }
+            result => return result,
+        }
+    }
+    probe()
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use std::os::unix::process::ExitStatusExt;
+    use std::process::{ExitStatus, Output};
+
+    use assert2::{check, let_assert};
+
+    use super::*;
+
+    fn command_fail() -> CommandError {
+        CommandError::CommandFail {
+            command: String::from("docker version"),
+            output: Output {
+                status: ExitStatus::from_raw(1 << 8),
+                stdout: vec![],
+                stderr: b"Cannot connect to the Docker daemon".to_vec(),
+            },
+        }
+    }
+
+    #[test]
+    fn should_retry_until_success() {
+        let mut attempts = 0;
+        let result = retry_probe(&[Duration::ZERO; 3], || {
+            attempts += 1;
+            if attempts < 3 {
+                Err(command_fail())
+            } else {
+                Ok(42)
+            }
+        });
+        let_assert!(Ok(42) = result);
+        check!(attempts == 3);
+    }
+
+    #[test]
+    fn should_stop_retrying() {
+        let mut attempts = 0;
+        let result = retry_probe::<()>(&[Duration::ZERO; 3], || {
+            attempts += 1;
+            Err(command_fail())
+        });
+        let_assert!(Err(CommandError::CommandFail { .. }) = result);
+        check!(attempts == 4);
+    }
+
+    #[test]
+    fn should_not_retry_missing_command() {
+        let mut attempts = 0;
+        let result = retry_probe::<()>(&[Duration::ZERO; 3], || {
+            attempts += 1;
+            Err(CommandError::CommandProcessError {
+                command: String::from("docker version"),
+                source: std::io::Error::from(std::io::ErrorKind::NotFound),
+            })
+        });
+        let_assert!(Err(CommandError::CommandProcessError { .. }) = result);
+        check!(attempts == 1);
+    }
+}
@@ -0,0 +1,166 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, PoisonError, Weak};
+
+use crate::runner::{Runner, RunnerInstance};
+use crate::ContainerId;
+
+/// The containers started in the current process, see [`Runner::stop_all`]
+pub(crate) static CONTAINER_REGISTRY: ContainerRegistry = ContainerRegistry::new();
+
+/// A registry of started containers
+///
+/// It only keeps weak references, so a dropped container is ignored.
+#[derive(Debug)]
+pub(crate) struct ContainerRegistry(Mutex<Vec<RegisteredContainer>>);
+
+#[derive(Debug)]
+struct RegisteredContainer {
+    instance: RunnerInstance,
+    id: ContainerId,
+    detached: Weak<AtomicBool>,
+    stopped: Weak<AtomicBool>,
+}
+
+impl RegisteredContainer {
+    fn is_alive(&self) -> bool {
+        self.stopped.strong_count() > 0
+    }
+}
+
+impl ContainerRegistry {
+    pub(crate) const fn new() -> Self {
+        Self(Mutex::new(Vec::new()))
+    }
+
+    /// Track a started container
+    pub(crate) fn register(
+        &self,
+        runner: &Runner,
+        id: ContainerId,
+        detached: &Arc<AtomicBool>,
+        stopped: &Arc<AtomicBool>,
+    ) {
+        let mut containers = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        containers.retain(RegisteredContainer::is_alive);
+        containers.push(RegisteredContainer {
+            instance: runner.instance().clone(),
+            id,
+            detached: Arc::downgrade(detached),
+            stopped: Arc::downgrade(stopped),
+        });
+    }
+
+    /// Take the containers started by the runner instance
+    ///
+    /// The dropped, detached, and already stopped containers are skipped,
+    /// the returned containers are marked as stopped, so they are not stopped again during the drop.
+    pub(crate) fn take_running(&self, runner: &Runner) -> Vec<ContainerId> {
+        let mut containers = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        containers.retain(RegisteredContainer::is_alive);
+
+        let mut result = vec![];
+        containers.retain(|container| {
+            if !container.instance.is_same(runner.instance()) {
+                return true;
+            }
+            let detached = container
+                .detached
+                .upgrade()
+                .is_some_and(|detached| detached.load(Ordering::Acquire));
+            if detached {
+                return true;
+            }
+            let Some(stopped) = container.stopped.upgrade() else {
+                return false;
+            };
+            if !stopped.swap(true, Ordering::AcqRel) {
+                result.push(container.id);
+            }
+            false
+        });
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::check;
+
+    use super::*;
+    use crate::runner::{Nerdctl, RunnerConfig};
+    use crate::version::Version;
+
+    fn nerdctl(namespace: &str) -> Runner {
+        Runner::Nerdctl(Nerdctl {
+            version: Version::new(1, 7),
+            config: Arc::new(RunnerConfig {
+                namespace: Some(namespace.to_string()),
+                ..RunnerConfig::default()
+            }),
+        })
+    }
+
+    fn new_id(value: &str) -> ContainerId {
+        #[allow(clippy::unwrap_used)]
+        value.parse().unwrap()
+    }
+
+    fn new_flags(detached: bool, stopped: bool) -> (Arc<AtomicBool>, Arc<AtomicBool>) {
+        (
+            Arc::new(AtomicBool::new(detached)),
+            Arc::new(AtomicBool::new(stopped)),
+        )
+    }
+
+    #[test]
+    fn should_take_running_containers_once() {
+        let registry = ContainerRegistry::new();
+        let runner = nerdctl("test");
+
+        let running_id = new_id("7a1b2c3d4e5f");
+        let (running_detached, running_stopped) = new_flags(false, false);
+        registry.register(&runner, running_id, &running_detached, &running_stopped);
+
+        let (detached, stopped) = new_flags(true, false);
+        registry.register(&runner, new_id("8a1b2c3d4e5f"), &detached, &stopped);
+
+        let (stopped_detached, stopped) = new_flags(false, true);
+        registry.register(&runner, new_id("9a1b2c3d4e5f"), &stopped_detached, &stopped);
+
+        let (dropped_detached, dropped_stopped) = new_flags(false, false);
+        registry.register(
+            &runner,
+            new_id("aa1b2c3d4e5f"),
+            &dropped_detached,
+            &dropped_stopped,
+        );
+        drop(dropped_stopped);
+
+        let result = registry.take_running(&runner);
+        check!(result == vec![running_id]);
+        check!(running_stopped.load(Ordering::Acquire));
+
+        let result = registry.take_running(&runner);
+        check!(result.is_empty());
+    }
+
+    #[test]
+    fn should_take_only_runner_containers() {
+        let registry = ContainerRegistry::new();
+        let runner = nerdctl("test");
+        // Another instance with the same settings
+        let other_runner = nerdctl("test");
+        check!(runner == other_runner);
+
+        let id = new_id("7a1b2c3d4e5f");
+        let (detached, stopped) = new_flags(false, false);
+        registry.register(&runner, id, &detached, &stopped);
+        let other_id = new_id("8a1b2c3d4e5f");
+        let (other_detached, other_stopped) = new_flags(false, false);
+        registry.register(&other_runner, other_id, &other_detached, &other_stopped);
+
+        check!(registry.take_running(&runner.clone()) == vec![id]);
+        check!(registry.take_running(&runner).is_empty());
+        check!(registry.take_running(&other_runner) == vec![other_id]);
+    }
+}
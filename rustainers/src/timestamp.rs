@@ -0,0 +1,112 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Parse a RFC 3339 timestamp, as provided by the runners inspect command
+///
+/// The format is `<year>-<month>-<day>T<hour>:<minute>:<second>[.<fraction>](Z|+hh:mm|-hh:mm)`,
+/// the fraction could have up to nine digits (nanoseconds), the extra digits are ignored.
+pub(crate) fn parse_rfc3339(str: &str) -> Option<SystemTime> {
+    let (date, time) = str.trim().split_once(['T', 't', ' '])?;
+
+    // Date
+    let mut date_parts = date.splitn(3, '-');
+    let year = date_parts.next()?.parse::<i64>().ok()?;
+    let month = parse_in_range(date_parts.next()?, 1, 12)?;
+    let day = parse_in_range(date_parts.next()?, 1, 31)?;
+
+    // Offset
+    let (time, offset) = if let Some(time) = time.strip_suffix(['Z', 'z']) {
+        (time, 0)
+    } else {
+        let index = time.rfind(['+', '-'])?;
+        let (time, offset) = time.split_at(index);
+        let sign = if offset.starts_with('-') { -1 } else { 1 };
+        let (hours, minutes) = offset.get(1..)?.split_once(':')?;
+        let hours = parse_in_range(hours, 0, 23)?;
+        let minutes = parse_in_range(minutes, 0, 59)?;
+        (time, sign * (hours * 3600 + minutes * 60))
+    };
+
+    // Time
+    let (time, fraction) = time.split_once('.').unwrap_or((time, ""));
+    let mut time_parts = time.splitn(3, ':');
+    let hours = parse_in_range(time_parts.next()?, 0, 23)?;
+    let minutes = parse_in_range(time_parts.next()?, 0, 59)?;
+    let seconds = parse_in_range(time_parts.next()?, 0, 60)?;
+    let nanos = parse_nanos(fraction)?;
+
+    let days = days_from_civil(year, month, day);
+    let secs = days * SECONDS_PER_DAY + hours * 3600 + minutes * 60 + seconds - offset;
+    if let Ok(secs) = u64::try_from(secs) {
+        UNIX_EPOCH.checked_add(Duration::new(secs, nanos))
+    } else {
+        let before = u64::try_from(-secs).ok()?;
+        UNIX_EPOCH
+            .checked_sub(Duration::from_secs(before))?
+            .checked_add(Duration::from_nanos(nanos.into()))
+    }
+}
+
+fn parse_in_range(str: &str, min: i64, max: i64) -> Option<i64> {
+    let value = str.parse::<i64>().ok()?;
+    (min..=max).contains(&value).then_some(value)
+}
+
+fn parse_nanos(fraction: &str) -> Option<u32> {
+    if !fraction.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+    let digits = fraction.get(..9).unwrap_or(fraction);
+    let padded = format!("{digits:0<9}");
+    padded.parse().ok()
+}
+
+// See <http://howardhinnant.github.io/date_algorithms.html#days_from_civil>
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = if year >= 0 { year } else { year - 399 } / 400;
+    let year_of_era = year - era * 400;
+    let month_index = (month + 9) % 12;
+    let day_of_year = (153 * month_index + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::epoch("1970-01-01T00:00:00Z", 0, 0)]
+    #[case::docker("2023-10-31T08:32:14.261437625Z", 1_698_741_134, 261_437_625)]
+    #[case::podman("2023-08-25T12:47:17.861262357+02:00", 1_692_960_437, 861_262_357)]
+    #[case::negative_offset("2023-08-25T08:47:17.5-02:00", 1_692_960_437, 500_000_000)]
+    #[case::leap_year("2024-02-29T00:00:00Z", 1_709_164_800, 0)]
+    fn should_parse_rfc3339(#[case] str: &str, #[case] secs: u64, #[case] nanos: u32) {
+        let result = parse_rfc3339(str);
+        let_assert!(Some(time) = result);
+        check!(time == UNIX_EPOCH + Duration::new(secs, nanos));
+    }
+
+    #[test]
+    fn should_parse_before_epoch() {
+        let result = parse_rfc3339("0001-01-01T00:00:00Z");
+        let_assert!(Some(time) = result);
+        check!(time < UNIX_EPOCH);
+    }
+
+    #[rstest]
+    #[case::empty("")]
+    #[case::no_time("2023-10-31")]
+    #[case::no_offset("2023-10-31T08:32:14")]
+    #[case::invalid_month("2023-13-31T08:32:14Z")]
+    #[case::invalid_fraction("2023-10-31T08:32:14.12aZ")]
+    fn should_not_parse_invalid_rfc3339(#[case] str: &str) {
+        let result = parse_rfc3339(str);
+        check!(result.is_none());
+    }
+}
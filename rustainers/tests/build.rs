@@ -0,0 +1,71 @@
+//! Build image tests.
+
+use assert2::{check, let_assert};
+use rstest::rstest;
+
+use rustainers::compose::{TemporaryDirectory, TemporaryFile};
+use rustainers::runner::{RunOption, Runner, RunnerError};
+use rustainers::BuildImage;
+
+mod common;
+pub use self::common::*;
+
+#[rstest]
+#[tokio::test]
+async fn should_run_built_image(runner: &Runner) -> anyhow::Result<()> {
+    let image = BuildImage::builder()
+        .with_context(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/assets/build"))
+        .build();
+    let options = RunOption::builder().with_remove(true).build();
+    let container = runner.start_with_options(image, options).await?;
+
+    let result = runner.exec(&container, ["cat", "/hello.txt"]).await?;
+    check!(result.trim() == "Hello from a built image");
+
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn should_stream_build_output(runner: &Runner) -> anyhow::Result<()> {
+    let image = BuildImage::builder()
+        .with_context(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/assets/build"))
+        .build();
+
+    let mut stream = runner.build_stream(&image).await?;
+    let mut lines = vec![];
+    while let Some(line) = stream.next_line().await {
+        lines.push(line);
+    }
+    check!(!lines.is_empty());
+    stream.result().await?;
+
+    let options = RunOption::builder().with_remove(true).build();
+    let container = runner.start_with_options(image, options).await?;
+    let result = runner.exec(&container, ["cat", "/hello.txt"]).await?;
+    check!(result.trim() == "Hello from a built image");
+
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn should_stream_failing_build_result(runner: &Runner) -> anyhow::Result<()> {
+    let temp_dir = TemporaryDirectory::with_files(
+        "failing-build",
+        [TemporaryFile::builder()
+            .with_path("Dockerfile")
+            .with_content("FROM docker.io/alpine\nRUN false\n")
+            .build()],
+    )
+    .await?;
+    let image = BuildImage::builder()
+        .with_context(temp_dir.as_ref())
+        .build();
+
+    let stream = runner.build_stream(&image).await?;
+    let result = stream.result().await;
+    let_assert!(Err(RunnerError::BuildError { .. }) = result);
+
+    Ok(())
+}
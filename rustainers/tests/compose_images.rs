@@ -1,8 +1,57 @@
 //! Compose images.
 
+use rustainers::compose::{
+    RunnableComposeContainers, RunnableComposeContainersBuilder, TemporaryDirectory, TemporaryFile,
+    ToRunnableComposeContainers,
+};
+use rustainers::WaitStrategy;
+
 mod common;
 pub use self::common::*;
 
+/// A compose file in a temporary directory, with optional wait strategies
+#[derive(Debug, Clone)]
+struct ComposeFile {
+    dir: TemporaryDirectory,
+    wait_strategies: Vec<(&'static str, WaitStrategy)>,
+}
+
+impl ComposeFile {
+    async fn new(content: &str) -> anyhow::Result<Self> {
+        let dir = TemporaryDirectory::with_files(
+            "compose",
+            [TemporaryFile::builder()
+                .with_path("docker-compose.yaml")
+                .with_content(content)
+                .build()],
+        )
+        .await?;
+        Ok(Self {
+            dir,
+            wait_strategies: vec![],
+        })
+    }
+
+    fn with_wait_strategy(mut self, service: &'static str, strategy: WaitStrategy) -> Self {
+        self.wait_strategies.push((service, strategy));
+        self
+    }
+}
+
+impl ToRunnableComposeContainers for ComposeFile {
+    type AsPath = TemporaryDirectory;
+
+    fn to_runnable(
+        &self,
+        builder: RunnableComposeContainersBuilder<Self::AsPath>,
+    ) -> RunnableComposeContainers<Self::AsPath> {
+        builder
+            .with_compose_path(self.dir.clone())
+            .with_wait_strategies(self.wait_strategies.clone())
+            .build()
+    }
+}
+
 #[cfg(feature = "very-long-tests")]
 mod kafka {
 
@@ -30,6 +79,7 @@ mod kafka {
 }
 
 mod redpanda {
+    use assert2::{check, let_assert};
     use rstest::rstest;
     use tracing::debug;
 
@@ -48,6 +98,203 @@ mod redpanda {
         debug!("Started {containers}");
         containers.broker_address().await?;
         containers.schema_registry_endpoint().await?;
+        containers.proxy_endpoint().await?;
+        containers.admin_endpoint().await?;
+
+        let mappings = containers.port_mappings().await?;
+        check!(mappings.len() == 5);
+        let_assert!(
+            Some((_, _, host_port)) = mappings
+                .iter()
+                .find(|(service, container_port, _)| service == "redpanda-0"
+                    && *container_port == 19092)
+        );
+        check!(containers
+            .broker_address()
+            .await?
+            .ends_with(&format!(":{host_port}")));
+
+        let console = containers.console_endpoint().await?;
+        let response = reqwest::get(format!("{console}/admin/health")).await?;
+        check!(response.status().is_success());
+
+        Ok(())
+    }
+}
+
+mod log_match {
+    use assert2::{check, let_assert};
+    use rstest::rstest;
+    use tracing::debug;
+
+    use rustainers::compose::ComposeRunOption;
+    use rustainers::runner::Runner;
+    use rustainers::{ContainerHealth, ContainerStatus, WaitStrategy};
+    use ulid::Ulid;
+
+    pub use super::*;
+
+    const COMPOSE: &str = r#"
+services:
+  worker:
+    image: docker.io/alpine
+    command: ["sh", "-c", "sleep 1; echo 'Worker ready'; sleep 600"]
+"#;
+
+    async fn log_readiness() -> anyhow::Result<ComposeFile> {
+        let compose = ComposeFile::new(COMPOSE).await?;
+        Ok(compose.with_wait_strategy("worker", WaitStrategy::stdout_contains("Worker ready")))
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_compose_wait_log_match(runner: &Runner) -> anyhow::Result<()> {
+        let containers = runner.compose_start(log_readiness().await?).await?;
+        debug!("Started {containers:?}");
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_compose_service_states(runner: &Runner) -> anyhow::Result<()> {
+        let containers = runner.compose_start(log_readiness().await?).await?;
+        let states = containers.service_states().await?;
+        let_assert!([worker] = states.as_slice());
+        check!(worker.service == "worker");
+        check!(worker.state == ContainerStatus::Running);
+        check!(worker.health == ContainerHealth::Unknown);
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_compose_exec(runner: &Runner) -> anyhow::Result<()> {
+        let containers = runner.compose_start(log_readiness().await?).await?;
+        let_assert!(Some(id) = containers.service_container_id("worker").await?);
+        let result = runner.exec_by_id(id, ["echo", "hello"]).await?;
+        check!(result == "hello\n");
+
+        let missing = containers.service_container_id("missing").await?;
+        check!(missing.is_none());
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_compose_down(runner: &Runner) -> anyhow::Result<()> {
+        let containers = runner.compose_start(log_readiness().await?).await?;
+        let mut observer = containers.clone();
+        observer.detach();
+
+        containers.down().await?;
+        let states = observer.service_states().await?;
+        check!(states.is_empty());
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_compose_reuse(runner: &Runner) -> anyhow::Result<()> {
+        let compose = log_readiness().await?;
+        let options = ComposeRunOption::builder()
+            .with_project_name(format!("reuse-{}", Ulid::new().to_string().to_lowercase()))
+            .with_reuse(true)
+            .build();
+
+        let containers = runner
+            .compose_start_with_options(compose.clone(), options.clone())
+            .await?;
+        let states = containers.service_states().await?;
+
+        let reused = runner.compose_start_with_options(compose, options).await?;
+        let reused_states = reused.service_states().await?;
+        check!(states == reused_states);
+
+        // The reused containers are not stopped
+        drop(reused);
+        let states_after_drop = containers.service_states().await?;
+        check!(states == states_after_drop);
+
+        Ok(())
+    }
+
+    const COMPOSE_HEALTH_CHECKS: &str = r#"
+services:
+  worker:
+    image: docker.io/alpine
+    command: ["sh", "-c", "sleep 2; touch /tmp/ready; sleep 600"]
+    healthcheck:
+      test: ["CMD", "test", "-f", "/tmp/ready"]
+      interval: 1s
+      retries: 30
+  sidecar:
+    image: docker.io/alpine
+    command: ["sleep", "600"]
+"#;
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_compose_wait_all_healthy(runner: &Runner) -> anyhow::Result<()> {
+        let compose = ComposeFile::new(COMPOSE_HEALTH_CHECKS).await?;
+        let options = ComposeRunOption::builder()
+            .with_wait_all_healthy(true)
+            .build();
+
+        let containers = runner.compose_start_with_options(compose, options).await?;
+        let states = containers.service_states().await?;
+        let_assert!(Some(worker) = states.iter().find(|it| it.service == "worker"));
+        check!(worker.health == ContainerHealth::Healthy);
+        let_assert!(Some(sidecar) = states.iter().find(|it| it.service == "sidecar"));
+        check!(sidecar.health == ContainerHealth::Unknown);
+
+        Ok(())
+    }
+}
+
+mod config {
+    use assert2::{check, let_assert};
+    use rstest::rstest;
+
+    use rustainers::compose::ComposeRunOption;
+    use rustainers::runner::Runner;
+
+    pub use super::*;
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_compose_config(runner: &Runner) -> anyhow::Result<()> {
+        let compose = ComposeFile::new(
+            r#"
+services:
+  web:
+    image: "docker.io/nginx:${NGINX_TAG}"
+"#,
+        )
+        .await?;
+        let options = ComposeRunOption::builder()
+            .with_env([("NGINX_TAG", "1.25")])
+            .build();
+
+        let result = runner
+            .compose_config_with_options(&compose.dir, &options)
+            .await;
+        let_assert!(Ok(config) = result);
+        check!(config.contains("docker.io/nginx:1.25"));
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_compose_config_invalid(runner: &Runner) -> anyhow::Result<()> {
+        let compose = ComposeFile::new("services:\n  web:\n    plop: 42\n").await?;
+
+        let result = runner.compose_config(&compose.dir).await;
+        check!(result.is_err());
 
         Ok(())
     }
@@ -1,13 +1,20 @@
 //! Custom container tests.
 
-use assert2::let_assert;
+use std::sync::{Arc, Mutex};
+
+use assert2::{check, let_assert};
 use rstest::rstest;
 
-use rustainers::runner::{RunOption, Runner};
+use rustainers::images::Alpine;
+use rustainers::runner::{
+    CgroupNamespace, ContainerError, LabelFilter, RunOption, Runner, RunnerError,
+};
 use rustainers::{
-    ContainerStatus, ImageName, RunnableContainer, RunnableContainerBuilder, ToRunnableContainer,
+    ContainerGuard, ContainerId, ContainerStatus, ImageName, RunnableContainer,
+    RunnableContainerBuilder, ToRunnableContainer, WaitStrategy,
 };
 use tracing::error;
+use ulid::Ulid;
 
 mod common;
 pub use self::common::*;
@@ -37,3 +44,577 @@ async fn should_run_hello_world(runner: &Runner) {
     }
     let_assert!(Ok(_) = result);
 }
+
+#[derive(Debug, Clone, Copy)]
+struct EchoAndExit;
+
+impl ToRunnableContainer for EchoAndExit {
+    fn to_runnable(&self, builder: RunnableContainerBuilder) -> RunnableContainer {
+        builder
+            .with_image(ImageName::new("docker.io/alpine"))
+            .with_command(["sh", "-c", "echo hello; exit 3"])
+            .build()
+    }
+}
+
+#[rstest]
+#[tokio::test]
+async fn should_run_once(runner: &Runner) -> anyhow::Result<()> {
+    let (exit_code, stdout) = runner.run_once(EchoAndExit).await?;
+    check!(exit_code == 3);
+    check!(stdout == "hello\n");
+
+    Ok(())
+}
+
+/// An image with a state updated after the start
+#[derive(Debug, Default)]
+struct Stateful {
+    greetings: Vec<String>,
+}
+
+impl ToRunnableContainer for Stateful {
+    fn to_runnable(&self, builder: RunnableContainerBuilder) -> RunnableContainer {
+        Alpine.to_runnable(builder)
+    }
+}
+
+#[rstest]
+#[tokio::test]
+async fn should_access_mutable_image(runner: &Runner) -> anyhow::Result<()> {
+    let options = RunOption::builder().with_remove(true).build();
+    let mut container = runner
+        .start_with_options(Stateful::default(), options)
+        .await?;
+    check!(container.image().greetings.is_empty());
+
+    let greeting = runner.exec(&container, ["echo", "hello"]).await?;
+    container.image_mut().greetings.push(greeting);
+    check!(container.greetings == ["hello\n"]);
+
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn should_provide_exit_code(runner: &Runner) -> anyhow::Result<()> {
+    let container = runner.start(Alpine).await?;
+    let_assert!(Ok(None) = container.exit_code().await);
+
+    let container = runner.start(HelloWorld).await?;
+    let_assert!(Ok(Some(0)) = container.exit_code().await);
+
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn should_provide_creation_and_start_times(runner: &Runner) -> anyhow::Result<()> {
+    let container = runner
+        .start_with_options(HelloWorld, RunOption::default())
+        .await?;
+
+    let created_at = container.created_at().await?;
+    let started_at = container.started_at().await?;
+    check!(created_at <= started_at);
+    check!(started_at <= std::time::SystemTime::now());
+
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn should_stop_with_default_stop_timeout(runner: &Runner) -> anyhow::Result<()> {
+    let runner = runner
+        .clone()
+        .with_default_stop_timeout(std::time::Duration::from_secs(1));
+    let container = runner
+        .start_with_options(HelloWorld, RunOption::default())
+        .await?;
+
+    let result = runner.stop(&container);
+    let_assert!(Ok(()) = result);
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy)]
+struct NeverReady;
+
+impl ToRunnableContainer for NeverReady {
+    fn to_runnable(&self, builder: RunnableContainerBuilder) -> RunnableContainer {
+        builder
+            .with_image(ImageName::new("docker.io/alpine"))
+            .with_command(["tail", "-f", "/dev/null"])
+            .with_wait_strategy(WaitStrategy::stdout_contains("never printed"))
+            .build()
+    }
+}
+
+#[rstest]
+#[tokio::test]
+async fn should_timeout_start(runner: &Runner) {
+    let name = format!("start-timeout-{}", Ulid::new().to_string().to_lowercase());
+    let options = RunOption::builder()
+        .with_remove(true)
+        .with_name(name.clone())
+        .with_start_timeout(std::time::Duration::from_secs(3))
+        .build();
+
+    let result = runner.start_with_options(NeverReady, options).await;
+    let_assert!(Err(RunnerError::StartError { source, .. }) = result);
+    let_assert!(ContainerError::StartTimeout(timeout) = *source);
+    check!(timeout == std::time::Duration::from_secs(3));
+
+    // The container is removed
+    let result = runner.attach_existing(&name).await;
+    let_assert!(Err(RunnerError::AttachError { .. }) = result);
+}
+
+#[rstest]
+#[tokio::test]
+async fn should_wait_removed(runner: &Runner) -> anyhow::Result<()> {
+    let options = RunOption::builder().with_remove(true).build();
+    let container = runner.start_with_options(Alpine, options).await?;
+
+    runner.stop(&container)?;
+    let result = container.wait_removed().await;
+    let_assert!(Ok(()) = result);
+
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn should_keep_on_failure(runner: &Runner) -> anyhow::Result<()> {
+    let options = RunOption::builder()
+        .with_remove(true)
+        .with_keep_on_failure(true)
+        // exit by itself, the container is not stopped by the drop
+        .with_command(["sleep", "30"])
+        .build();
+    let container = runner.start_with_options(Alpine, options).await?;
+    let id = container.id();
+
+    // Drop the container while panicking
+    let result = std::thread::spawn(move || {
+        let _container = container;
+        panic!("simulate a test failure");
+    })
+    .join();
+    check!(result.is_err());
+
+    let processes = runner
+        .list_running_containers(&LabelFilter::default())
+        .await?;
+    check!(processes.iter().any(|it| it.id() == id));
+
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn should_run_with_private_cgroupns(runner: &Runner) -> anyhow::Result<()> {
+    let options = RunOption::builder()
+        .with_remove(true)
+        .with_cgroupns(CgroupNamespace::Private)
+        .build();
+    let container = runner.start_with_options(Alpine, options).await?;
+
+    // With cgroups v2, the container is at the root of its cgroup namespace
+    let result = runner.exec(&container, ["cat", "/proc/1/cgroup"]).await?;
+    check!(result.trim() == "0::/");
+
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn should_call_on_started(runner: &Runner) -> anyhow::Result<()> {
+    let started = Arc::new(Mutex::new(None));
+    let options = RunOption::builder()
+        .with_remove(true)
+        .with_on_started({
+            let started = Arc::clone(&started);
+            move |id: &ContainerId| {
+                if let Ok(mut started) = started.lock() {
+                    *started = Some(*id);
+                }
+            }
+        })
+        .build();
+    let container = runner.start_with_options(Alpine, options).await?;
+
+    let_assert!(Ok(started) = started.lock());
+    check!(*started == Some(container.id()));
+
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn should_run_with_name_prefix(runner: &Runner) -> anyhow::Result<()> {
+    let options = RunOption::builder()
+        .with_remove(true)
+        .with_name_prefix("ci")
+        .build();
+    let container = runner.start_with_options(Alpine, options).await?;
+
+    let inspect = container.inspect_raw().await?;
+    let_assert!(Some(name) = inspect.get("Name").and_then(|it| it.as_str()));
+    check!(name.trim_start_matches('/').starts_with("ci-"));
+
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn should_get_container_config(runner: &Runner) -> anyhow::Result<()> {
+    let options = RunOption::builder().with_remove(true).build();
+    let container = runner.start_with_options(Alpine, options).await?;
+
+    let config = container.container_config().await?;
+    check!(config.cmd() == ["tail", "-f", "/dev/null"]);
+    check!(config.env().contains_key("PATH"));
+
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn should_run_with_init(runner: &Runner) -> anyhow::Result<()> {
+    let options = RunOption::builder()
+        .with_remove(true)
+        .with_init(true)
+        .build();
+    let container = runner.start_with_options(Alpine, options).await?;
+
+    // The init process is the PID 1, instead of the command
+    let result = runner.exec(&container, ["cat", "/proc/1/comm"]).await?;
+    check!(result.trim() != "tail");
+
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn should_run_with_sysctls(runner: &Runner) -> anyhow::Result<()> {
+    let options = RunOption::builder()
+        .with_remove(true)
+        .with_sysctl("net.ipv4.ip_unprivileged_port_start", "80")
+        .with_sysctl("kernel.shmmni", "1024")
+        .build();
+    let container = runner.start_with_options(Alpine, options).await?;
+
+    let result = runner
+        .exec(
+            &container,
+            ["sysctl", "-n", "net.ipv4.ip_unprivileged_port_start"],
+        )
+        .await?;
+    check!(result.trim() == "80");
+    let result = runner
+        .exec(&container, ["sysctl", "-n", "kernel.shmmni"])
+        .await?;
+    check!(result.trim() == "1024");
+
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn should_run_with_group_add(runner: &Runner) -> anyhow::Result<()> {
+    let options = RunOption::builder()
+        .with_remove(true)
+        .with_group_add("video")
+        .with_group_add("4242")
+        .build();
+    let container = runner.start_with_options(Alpine, options).await?;
+
+    let result = runner.exec(&container, ["id", "-G"]).await?;
+    let groups = result.split_whitespace().collect::<Vec<_>>();
+    check!(groups.contains(&"27")); // video
+    check!(groups.contains(&"4242"));
+
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn should_run_with_oom_score_adj(runner: &Runner) -> anyhow::Result<()> {
+    let options = RunOption::builder()
+        .with_remove(true)
+        .with_oom_score_adj(500)
+        .build();
+    let container = runner.start_with_options(Alpine, options).await?;
+
+    let result = runner
+        .exec(&container, ["cat", "/proc/1/oom_score_adj"])
+        .await?;
+    check!(result.trim() == "500");
+
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn should_run_with_tty(runner: &Runner) -> anyhow::Result<()> {
+    let options = RunOption::builder()
+        .with_remove(true)
+        .with_tty(true)
+        .with_interactive(true)
+        .build();
+    let container = runner.start_with_options(Alpine, options).await?;
+
+    let result = runner
+        .exec(&container, ["readlink", "/proc/1/fd/0"])
+        .await?;
+    check!(result.starts_with("/dev/pts/"));
+
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn should_mount_docker_socket(runner: &Runner) -> anyhow::Result<()> {
+    let options = RunOption::builder()
+        .with_remove(true)
+        .with_docker_socket()
+        .build();
+    let container = runner.start_with_options(Alpine, options).await?;
+
+    let result = runner
+        .exec(&container, ["test", "-S", "/var/run/docker.sock"])
+        .await;
+    let_assert!(Ok(_) = result);
+
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn should_attach_existing(runner: &Runner) -> anyhow::Result<()> {
+    let name = format!("attach-{}", std::process::id());
+    let options = RunOption::builder()
+        .with_remove(true)
+        .with_name(name.as_str())
+        .build();
+    let container = runner.start_with_options(Alpine, options).await?;
+
+    let attached = runner.attach_existing(&name).await?;
+    check!(attached.id() == container.id());
+    // The attached container is detached, the drop does not stop it
+    drop(attached);
+    let processes = runner
+        .list_running_containers(&LabelFilter::default())
+        .await?;
+    check!(processes.iter().any(|it| it.id() == container.id()));
+
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn should_not_attach_missing_container(runner: &Runner) {
+    let result = runner.attach_existing("rustainers-does-not-exist").await;
+    let_assert!(Err(RunnerError::AttachError { source, .. }) = result);
+    let_assert!(ContainerError::ContainerNotFound(_) = *source);
+}
+
+#[rstest]
+#[tokio::test]
+async fn should_list_running_containers(runner: &Runner) -> anyhow::Result<()> {
+    let options = RunOption::builder().with_remove(true).build();
+    let container = runner.start_with_options(Alpine, options).await?;
+
+    let processes = runner
+        .list_running_containers(&LabelFilter::default())
+        .await?;
+    let_assert!(Some(process) = processes.iter().find(|it| it.id() == container.id()));
+    check!(process.state() == ContainerStatus::Running);
+    check!(process.image().contains("alpine"));
+    check!(process.created_at().is_some());
+
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn should_cleanup_scoped_container(runner: &Runner) -> anyhow::Result<()> {
+    let container = runner.start_scoped(Alpine).await?;
+    let id = container.id();
+
+    container.cleanup().await?;
+    let processes = runner
+        .list_running_containers(&LabelFilter::default())
+        .await?;
+    check!(processes.iter().all(|it| it.id() != id));
+
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn should_cleanup_removed_container(runner: &Runner) -> anyhow::Result<()> {
+    let options = RunOption::builder().with_remove(true).build();
+    let container = runner.start_with_options(Alpine, options).await?;
+    let guard = ContainerGuard::from(container);
+
+    let result = guard.cleanup().await;
+    let_assert!(Ok(()) = result);
+
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn should_stop_all_containers(runner: &Runner) -> anyhow::Result<()> {
+    // Another runner instance, so the containers of the other tests are not stopped
+    let runner = match runner {
+        Runner::Docker(_) => Runner::docker()?,
+        Runner::Podman(_) => Runner::podman()?,
+        Runner::Nerdctl(_) => Runner::nerdctl()?,
+        _ => Runner::auto()?,
+    };
+    let first = runner.start(Alpine).await?;
+    let second = runner.start(Alpine).await?;
+    let detached = runner.start(Alpine).await?;
+    detached.detach();
+
+    runner.stop_all().await?;
+    let processes = runner
+        .list_running_containers(&LabelFilter::default())
+        .await?;
+    check!(processes.iter().all(|it| it.id() != first.id()));
+    check!(processes.iter().all(|it| it.id() != second.id()));
+    check!(processes.iter().any(|it| it.id() == detached.id()));
+
+    // Already stopped
+    runner.stop_all().await?;
+    drop(first);
+    drop(second);
+
+    let guard = ContainerGuard::from(detached);
+    guard.cleanup().await?;
+
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn should_use_host_address(runner: &Runner) -> anyhow::Result<()> {
+    let address = std::net::IpAddr::from([192, 168, 42, 1]);
+    let runner = runner.clone().with_host_address(address);
+
+    let host_ip = runner.container_host_ip().await?;
+    check!(host_ip == address);
+
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn should_exec_output(runner: &Runner) -> anyhow::Result<()> {
+    let options = RunOption::builder().with_remove(true).build();
+    let container = runner.start_with_options(Alpine, options).await?;
+
+    let output = runner
+        .exec_output(&container, ["sh", "-c", "echo out; echo err >&2; exit 2"])
+        .await?;
+    check!(output.exit_code == 2);
+    check!(!output.is_success());
+    check!(output.stdout == "out\n");
+    check!(output.stderr == "err\n");
+
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn should_read_file(runner: &Runner) -> anyhow::Result<()> {
+    let options = RunOption::builder().with_remove(true).build();
+    let container = runner.start_with_options(Alpine, options).await?;
+
+    let content = container.read_file("/etc/hostname").await?;
+    let hostname = runner.exec(&container, ["hostname"]).await?;
+    check!(content == hostname.into_bytes());
+
+    let result = container.read_file("/not/a/file").await;
+    let_assert!(Err(RunnerError::ReadFileError { source, .. }) = result);
+    check!(matches!(*source, ContainerError::FileNotFound { .. }));
+
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn should_write_file(runner: &Runner) -> anyhow::Result<()> {
+    let options = RunOption::builder().with_remove(true).build();
+    let container = runner.start_with_options(Alpine, options).await?;
+
+    let content = [0x00, 0xca, 0xfe, 0xba, 0xbe, b'\n'];
+    container.write_file("/tmp/data.bin", &content).await?;
+    let result = container.read_file("/tmp/data.bin").await?;
+    check!(result == content);
+
+    let result = container.write_file("/not/a/dir/data.bin", &content).await;
+    let_assert!(Err(RunnerError::WriteFileError { source, .. }) = result);
+    check!(matches!(*source, ContainerError::DirectoryNotFound { .. }));
+
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn should_stream_stats(runner: &Runner) -> anyhow::Result<()> {
+    let options = RunOption::builder().with_remove(true).build();
+    let container = runner.start_with_options(Alpine, options).await?;
+
+    let mut stats = runner.stats_stream(&container).await?;
+    for _ in 0..2 {
+        let sample = tokio::time::timeout(std::time::Duration::from_secs(10), stats.recv()).await?;
+        let_assert!(Some(sample) = sample);
+        check!(!sample.name().is_empty());
+        check!(sample.memory_percent().is_some());
+    }
+
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn should_tail_logs(runner: &Runner) -> anyhow::Result<()> {
+    let options = RunOption::builder()
+        .with_remove(true)
+        .with_command(["sh", "-c", "seq 1 100; sleep 600"])
+        .build();
+    let container = runner.start_with_options(Alpine, options).await?;
+    tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+
+    let logs = container.logs_tail(3).await?;
+    check!(logs == "98\n99\n100\n");
+
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn should_retrieve_logs_since(runner: &Runner) -> anyhow::Result<()> {
+    let options = RunOption::builder()
+        .with_remove(true)
+        .with_command(["sh", "-c", "echo before; sleep 2; echo after; sleep 600"])
+        .build();
+    let container = runner.start_with_options(Alpine, options).await?;
+    tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+    let event = std::time::SystemTime::now();
+    tokio::time::sleep(std::time::Duration::from_secs(2)).await;
+
+    let logs = container.logs_since(event).await?;
+    check!(logs == "after\n");
+    let logs = container
+        .logs_since(std::time::Duration::from_secs(60))
+        .await?;
+    check!(logs == "before\nafter\n");
+
+    Ok(())
+}
@@ -6,6 +6,7 @@ use assert2::check;
 use rstest::rstest;
 use ulid::Ulid;
 
+use rustainers::compose::{TemporaryDirectory, TemporaryFile};
 use rustainers::images::Alpine;
 use rustainers::runner::{RunOption, Runner};
 
@@ -43,3 +44,59 @@ async fn should_work_with_env(runner: &Runner) -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[rstest]
+#[tokio::test]
+#[allow(clippy::indexing_slicing)]
+async fn should_inspect_raw_env(runner: &Runner) -> anyhow::Result<()> {
+    let data = Ulid::new();
+
+    let options = RunOption::builder()
+        .with_remove(true)
+        .with_env([("TEST_ENV_DATA", data.to_string())])
+        .build();
+    let container = runner.start_with_options(Alpine, options).await?;
+
+    let json = container.inspect_raw().await?;
+    let env = json["Config"]["Env"]
+        .as_array()
+        .cloned()
+        .unwrap_or_default();
+    let expected = format!("TEST_ENV_DATA={data}");
+    check!(env.iter().any(|var| var.as_str() == Some(&expected)));
+
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+#[allow(clippy::indexing_slicing)]
+async fn should_work_with_env_file(runner: &Runner) -> anyhow::Result<()> {
+    let temp_dir = TemporaryDirectory::with_files(
+        "env-file",
+        [TemporaryFile::builder()
+            .with_path("test.env")
+            .with_content("TEST_ENV_FILE=from-file\nTEST_ENV_OVERRIDE=from-file\n")
+            .build()],
+    )
+    .await?;
+
+    let options = RunOption::builder()
+        .with_remove(true)
+        .with_env_file(temp_dir.as_ref().join("test.env"))
+        .with_env([("TEST_ENV_OVERRIDE", "from-env")])
+        .build();
+    let container = runner.start_with_options(Alpine, options).await?;
+
+    let result = runner.exec(&container, ["env"]).await?;
+    let vars = result
+        .lines()
+        .filter(|line| !line.is_empty())
+        .filter_map(|line| line.split_once('='))
+        .collect::<HashMap<_, _>>();
+
+    check!(vars["TEST_ENV_FILE"] == "from-file");
+    check!(vars["TEST_ENV_OVERRIDE"] == "from-env");
+
+    Ok(())
+}
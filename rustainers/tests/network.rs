@@ -1,8 +1,8 @@
 //! Network-related tests.
 
-use assert2::let_assert;
+use assert2::{check, let_assert};
 use rstest::rstest;
-use rustainers::runner::{RunOption, Runner};
+use rustainers::runner::{LabelFilter, RunOption, Runner};
 use ulid::Ulid;
 
 mod common;
@@ -15,7 +15,7 @@ use crate::images::Curl;
 async fn should_work_with_network(runner: &Runner) -> anyhow::Result<()> {
     let id = Ulid::new();
     // Create network
-    let name = format!("my_network_{id}",);
+    let name = format!("my_network_{id}");
     let network = runner.create_network(&name).await?;
 
     // Container A inside network
@@ -49,7 +49,7 @@ async fn should_work_with_network(runner: &Runner) -> anyhow::Result<()> {
 async fn should_work_with_network_ip(runner: &Runner) -> anyhow::Result<()> {
     let id = Ulid::new();
     // Create network
-    let name = format!("my_network_{id}",);
+    let name = format!("my_network_{id}");
     let network = runner.create_network(&name).await?;
 
     // Container A inside network
@@ -79,6 +79,153 @@ async fn should_work_with_network_ip(runner: &Runner) -> anyhow::Result<()> {
     Ok(())
 }
 
+#[rstest]
+#[tokio::test]
+async fn should_work_with_additional_networks(runner: &Runner) -> anyhow::Result<()> {
+    let id = Ulid::new();
+    // Create network
+    let name = format!("my_network_{id}");
+    let network = runner.create_network(&name).await?;
+
+    // Container A on the bridge, and inside the network
+    let options = RunOption::builder()
+        .with_name(format!("web-server_{id}"))
+        .with_remove(true)
+        .with_additional_networks([network.clone()])
+        .build();
+    let _container = runner
+        .start_with_options(InternalWebServer, options)
+        .await?;
+
+    let url = format!("http://web-server_{id}:80");
+    let result = images::curl(
+        runner,
+        url,
+        RunOption::builder()
+            .with_name(format!("curl_{id}"))
+            .with_network(network)
+            .build(),
+    )
+    .await;
+
+    let_assert!(Ok(()) = result);
+
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn should_connect_reused_container_to_additional_networks(
+    runner: &Runner,
+) -> anyhow::Result<()> {
+    let id = Ulid::new();
+    let name = format!("my_network_{id}");
+    let network = runner.create_network(&name).await?;
+
+    // Container A on the bridge only
+    let options = RunOption::builder()
+        .with_name(format!("web-server_{id}"))
+        .with_remove(true)
+        .build();
+    let container = runner
+        .start_with_options(InternalWebServer, options)
+        .await?;
+    container.detach();
+
+    // Reuse container A, and connect it to the network
+    let options = RunOption::builder()
+        .with_name(format!("web-server_{id}"))
+        .with_remove(true)
+        .with_additional_networks([network.clone()])
+        .build();
+    let _container = runner
+        .start_with_options(InternalWebServer, options)
+        .await?;
+
+    let url = format!("http://web-server_{id}:80");
+    let result = images::curl(
+        runner,
+        url,
+        RunOption::builder()
+            .with_name(format!("curl_{id}"))
+            .with_network(network)
+            .build(),
+    )
+    .await;
+
+    let_assert!(Ok(()) = result);
+
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn should_work_with_bridge_ip(runner: &Runner) -> anyhow::Result<()> {
+    let id = Ulid::new();
+
+    // Container A on the default bridge
+    let options = RunOption::builder()
+        .with_name(format!("web-server_{id}"))
+        .with_remove(true)
+        .build();
+    let container = runner
+        .start_with_options(InternalWebServer, options)
+        .await?;
+
+    let bridge_ip = runner.bridge_ip(&container).await?;
+    let url = format!("http://{bridge_ip}:80");
+    let result = images::curl(
+        runner,
+        url,
+        RunOption::builder().with_name(format!("curl_{id}")).build(),
+    )
+    .await;
+
+    let_assert!(Ok(()) = result);
+
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn should_work_with_pod(runner: &Runner) -> anyhow::Result<()> {
+    let id = Ulid::new();
+    // Create pod
+    let name = format!("my_pod_{id}");
+    let result = runner.create_pod(&name).await;
+    if !matches!(runner, Runner::Podman(_)) {
+        let_assert!(Err(_) = result);
+        return Ok(());
+    }
+    let pod = result?;
+
+    // Container A inside the pod
+    let options = RunOption::builder()
+        .with_name(format!("web-server_{id}"))
+        .with_remove(true)
+        .with_pod(pod.clone())
+        .build();
+    let _container = runner
+        .start_with_options(InternalWebServer, options)
+        .await?;
+
+    // Containers inside the pod share the network namespace
+    let url = "http://localhost:80".to_string();
+    let result = images::curl(
+        runner,
+        url,
+        RunOption::builder()
+            .with_name(format!("curl_{id}"))
+            .with_pod(pod)
+            .build(),
+    )
+    .await;
+
+    let_assert!(Ok(()) = result);
+
+    Ok(())
+}
+
 #[rstest]
 #[tokio::test]
 async fn should_work_dind(runner: &Runner) -> anyhow::Result<()> {
@@ -110,7 +257,7 @@ async fn should_work_dind(runner: &Runner) -> anyhow::Result<()> {
 async fn should_not_work_without_network(runner: &Runner) -> anyhow::Result<()> {
     let id = Ulid::new();
     // Create network
-    let name = format!("my_network_{id}",);
+    let name = format!("my_network_{id}");
     let network = runner.create_network(&name).await?;
 
     // Container A inside network
@@ -138,3 +285,34 @@ async fn should_not_work_without_network(runner: &Runner) -> anyhow::Result<()>
 
     Ok(())
 }
+
+#[rstest]
+#[tokio::test]
+async fn should_list_networks(runner: &Runner) -> anyhow::Result<()> {
+    let id = Ulid::new();
+    let name = format!("my_network_{id}");
+    let network = runner.create_network(&name).await?;
+
+    let networks = runner.list_networks(&LabelFilter::default()).await?;
+    check!(networks.contains(&network));
+
+    let filter = LabelFilter::default().with_label_filter("rustainers.test", id.to_string());
+    let networks = runner.list_networks(&filter).await?;
+    check!(networks.is_empty());
+
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn should_create_existing_network(runner: &Runner) -> anyhow::Result<()> {
+    let id = Ulid::new();
+    let name = format!("my_network_{id}");
+    let network = runner.create_network(&name).await?;
+
+    let result = runner.create_network(&name).await;
+    let_assert!(Ok(existing) = result);
+    check!(existing == network);
+
+    Ok(())
+}
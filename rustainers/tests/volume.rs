@@ -5,7 +5,7 @@ use rstest::rstest;
 use ulid::Ulid;
 
 use rustainers::compose::{TemporaryDirectory, TemporaryFile};
-use rustainers::runner::{RunOption, Runner};
+use rustainers::runner::{LabelFilter, RunOption, Runner};
 use rustainers::Volume;
 
 mod common;
@@ -45,6 +45,27 @@ async fn should_work_with_volume_mount_bind(runner: &Runner) -> anyhow::Result<(
     Ok(())
 }
 
+#[rstest]
+#[tokio::test]
+async fn should_work_with_volume_bind_content(runner: &Runner) -> anyhow::Result<()> {
+    let page = include_str!("assets/index.html");
+    let volume = Volume::bind_content(page, format!("{}/index.html", WebServer::STATIC_HTML))?;
+
+    // Bind mount the content
+    let options = RunOption::builder()
+        .with_remove(true)
+        .with_volumes([volume])
+        .build();
+    let image = WebServer::default();
+    let container = runner.start_with_options(image, options).await?;
+
+    let result = container.get("/index.html").await;
+    let_assert!(Ok(html) = result);
+    check!(html == page);
+
+    Ok(())
+}
+
 #[rstest]
 #[tokio::test]
 async fn should_work_with_volume(runner: &Runner) -> anyhow::Result<()> {
@@ -77,3 +98,20 @@ async fn should_work_with_volume(runner: &Runner) -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[rstest]
+#[tokio::test]
+async fn should_list_volumes(runner: &Runner) -> anyhow::Result<()> {
+    let id = Ulid::new();
+    let name = format!("volume_{id}");
+    let volume_name = runner.create_volume(&name).await?;
+
+    let volumes = runner.list_volumes(&LabelFilter::default()).await?;
+    check!(volumes.contains(&volume_name));
+
+    let filter = LabelFilter::default().with_label_key(format!("rustainers.test.{id}"));
+    let volumes = runner.list_volumes(&filter).await?;
+    check!(volumes.is_empty());
+
+    Ok(())
+}
@@ -8,7 +8,7 @@ use ulid::Ulid;
 
 use rustainers::images::GenericImage;
 use rustainers::runner::Runner;
-use rustainers::{ImageName, WaitStrategy};
+use rustainers::{ImageName, RunnableContainer, ToRunnableContainer, WaitStrategy};
 
 mod common;
 pub use self::common::*;
@@ -30,6 +30,23 @@ async fn should_wait_stdout_contains(runner: &Runner) -> anyhow::Result<()> {
     Ok(())
 }
 
+#[rstest]
+#[tokio::test]
+async fn should_wait_output_contains(runner: &Runner) -> anyhow::Result<()> {
+    let id = Ulid::new().to_string();
+
+    let mut image = GenericImage::new(ImageName::new("alpine"));
+    image.set_wait_strategy(WaitStrategy::output_contains(&id));
+    image.set_command(["sh", "-c", &format!("sleep 1 && echo {id} >&2")]);
+
+    let start = Instant::now();
+    let _container = runner.start(image).await?;
+    let duration = start.elapsed();
+    check!(duration > Duration::from_secs(1));
+
+    Ok(())
+}
+
 #[cfg(feature = "regex")]
 #[rstest]
 #[tokio::test]
@@ -49,3 +66,26 @@ async fn should_wait_stderr_match(runner: &Runner) -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[rstest]
+#[tokio::test]
+async fn should_measure_startup_duration(runner: &Runner) -> anyhow::Result<()> {
+    let id = Ulid::new().to_string();
+
+    let mut image = GenericImage::new(ImageName::new("alpine"));
+    image.set_wait_strategy(WaitStrategy::stdout_contains(&id));
+    image.set_command(["sh", "-c", &format!("sleep 1 && echo {id}")]);
+    let runnable = image.to_runnable(RunnableContainer::builder());
+    check!(matches!(
+        runnable.wait_strategies(),
+        [WaitStrategy::LogMatch { .. }]
+    ));
+
+    let start = Instant::now();
+    let container = runner.start(image).await?;
+    let duration = start.elapsed();
+    check!(container.startup_duration() > Duration::from_secs(1));
+    check!(container.startup_duration() <= duration);
+
+    Ok(())
+}
@@ -1,12 +1,16 @@
 //! Tests for waits.
 
 mod common;
-use assert2::let_assert;
+use std::time::{Duration, Instant};
+
+use assert2::{check, let_assert};
 use rstest::rstest;
 use tokio::io::AsyncWriteExt;
 use tokio::net::TcpStream;
 
+use rustainers::images::GenericImage;
 use rustainers::runner::Runner;
+use rustainers::{HealthCheck, ImageName, WaitStrategy};
 
 pub use self::common::*;
 use self::images::{Netcat, WebServer};
@@ -34,3 +38,41 @@ async fn should_wait_scan_port(runner: &Runner) -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[rstest]
+#[tokio::test]
+async fn should_wait_file_exists(runner: &Runner) -> anyhow::Result<()> {
+    let mut image = GenericImage::new(ImageName::new("docker.io/alpine"));
+    image.set_wait_strategy(WaitStrategy::file_exists("/tmp/ready"));
+    image.set_command([
+        "sh",
+        "-c",
+        "sleep 1 && touch /tmp/ready && tail -f /dev/null",
+    ]);
+
+    let start = Instant::now();
+    let _container = runner.start(image).await?;
+    let duration = start.elapsed();
+    check!(duration > Duration::from_secs(1));
+
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn should_report_exit_code_and_logs_when_container_dies(runner: &Runner) {
+    let mut image = GenericImage::new(ImageName::new("docker.io/alpine"));
+    image.set_wait_strategy(
+        HealthCheck::builder()
+            .with_command("true")
+            .with_interval(Duration::from_millis(200))
+            .build(),
+    );
+    image.set_command(["sh", "-c", "echo 'Boom!' && exit 3"]);
+
+    let result = runner.start(image).await;
+    let_assert!(Err(err) = result);
+    let message = err.to_string();
+    check!(message.contains("exit code: 3"));
+    check!(message.contains("Boom!"));
+}